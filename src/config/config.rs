@@ -19,12 +19,21 @@
 
 use super::CoreApplicationConfigTrait;
 use crate::setup::database::{DatabaseConfig, DbType};
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::client_id_scheme::ClientIdScheme;
+use crate::types::enums::notifier_type::NotifierType;
+use crate::types::enums::query_language::QueryLanguage;
+use crate::types::enums::signer_type::SignerType;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::types::enums::unreachable_policy::UnreachablePolicy;
 use crate::types::host::HostConfig;
+use crate::types::notifier::SmtpConfig;
 use crate::types::wallet::WalletConfig;
 use crate::utils::read;
 use serde::Serialize;
 use std::env;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 #[derive(Serialize, Clone, Debug)]
 pub struct CoreApplicationConfig {
@@ -33,7 +42,27 @@ pub struct CoreApplicationConfig {
     pub database_config: DatabaseConfig,
     pub ssi_wallet_config: WalletConfig,
     pub keys_path: String,
+    pub signer_type: SignerType,
+    pub signing_alg: SigningAlgorithm,
+    pub notifier_type: NotifierType,
+    pub smtp_config: SmtpConfig,
+    pub webhook_url: Option<String>,
+    #[serde(skip_serializing)]
+    pub admin_token: Option<String>,
+    pub reminder_interval: u64,
+    pub offer_by_value: bool,
+    pub did_cache_ttl: u64,
+    pub vp_query_language: QueryLanguage,
+    pub vp_client_id_scheme: ClientIdScheme,
+    pub vp_encrypt_response: bool,
+    pub vp_session_lifetime: u64,
+    pub clock_skew: u64,
+    pub status_unreachable_policy: UnreachablePolicy,
     pub openapi_path: String,
+    pub credentials_path: String,
+    // Loaded once so requests never read or compile the registry again
+    #[serde(skip)]
+    pub credential_registry: Arc<CredentialRegistry>,
     pub api_version: String,
 }
 
@@ -65,7 +94,31 @@ impl Default for CoreApplicationConfig {
             },
             is_local: true,
             keys_path: "static/certificates/".to_string(),
+            signer_type: SignerType::File,
+            signing_alg: SigningAlgorithm::RS256,
+            notifier_type: NotifierType::Smtp,
+            smtp_config: SmtpConfig {
+                host: "127.0.0.1".to_string(),
+                port: 1025,
+                user: None,
+                password: None,
+                from: "RainbowAuthority@rainbow.com".to_string(),
+                tls: false,
+            },
+            webhook_url: None,
+            admin_token: None,
+            reminder_interval: 3600,
+            offer_by_value: false,
+            did_cache_ttl: 300,
+            vp_query_language: QueryLanguage::Pex,
+            vp_client_id_scheme: ClientIdScheme::RedirectUri,
+            vp_encrypt_response: false,
+            vp_session_lifetime: 300,
+            clock_skew: 60,
+            status_unreachable_policy: UnreachablePolicy::Reject,
             openapi_path: "static/specs/openapi/openapi.json".to_string(),
+            credentials_path: "static/credentials/registry.json".to_string(),
+            credential_registry: Arc::new(CredentialRegistry::default()),
             api_version: "v1".to_string(),
         }
     }
@@ -82,7 +135,7 @@ impl CoreApplicationConfig {
 
         dotenvy::dotenv().ok();
         let default = CoreApplicationConfig::default();
-        let compound_config = Self {
+        let mut compound_config = Self {
             host: HostConfig {
                 protocol: extract_env("HOST_PROTOCOL", default.host.clone().protocol),
                 url: extract_env("HOST_URL", default.host.clone().url),
@@ -112,12 +165,86 @@ impl CoreApplicationConfig {
                 id: None,
             },
             keys_path: extract_env("KEYS_PATH", default.keys_path),
+            signer_type: extract_env("SIGNER_TYPE", default.signer_type.to_string())
+                .parse()
+                .unwrap(),
+            signing_alg: extract_env("SIGNING_ALG", default.signing_alg.to_string())
+                .parse()
+                .unwrap(),
+            notifier_type: extract_env("NOTIFIER_TYPE", default.notifier_type.to_string())
+                .parse()
+                .unwrap(),
+            smtp_config: SmtpConfig {
+                host: extract_env("SMTP_HOST", default.smtp_config.host),
+                port: extract_env("SMTP_PORT", default.smtp_config.port.to_string())
+                    .parse()
+                    .unwrap(),
+                user: option_extract_env("SMTP_USER"),
+                password: option_extract_env("SMTP_PASSWORD"),
+                from: extract_env("SMTP_FROM", default.smtp_config.from),
+                tls: extract_env("SMTP_TLS", default.smtp_config.tls.to_string())
+                    .parse()
+                    .unwrap(),
+            },
+            webhook_url: option_extract_env("NOTIFIER_WEBHOOK_URL"),
+            admin_token: option_extract_env("ADMIN_TOKEN"),
+            reminder_interval: extract_env(
+                "REMINDER_INTERVAL",
+                default.reminder_interval.to_string(),
+            )
+            .parse()
+            .unwrap(),
+            offer_by_value: extract_env("OFFER_BY_VALUE", default.offer_by_value.to_string())
+                .parse()
+                .unwrap(),
+            did_cache_ttl: extract_env("DID_CACHE_TTL", default.did_cache_ttl.to_string())
+                .parse()
+                .unwrap(),
+            vp_query_language: extract_env(
+                "VP_QUERY_LANGUAGE",
+                default.vp_query_language.to_string(),
+            )
+            .parse()
+            .unwrap(),
+            vp_client_id_scheme: extract_env(
+                "VP_CLIENT_ID_SCHEME",
+                default.vp_client_id_scheme.to_string(),
+            )
+            .parse()
+            .unwrap(),
+            vp_encrypt_response: extract_env(
+                "VP_ENCRYPT_RESPONSE",
+                default.vp_encrypt_response.to_string(),
+            )
+            .parse()
+            .unwrap(),
+            vp_session_lifetime: extract_env(
+                "VP_SESSION_LIFETIME",
+                default.vp_session_lifetime.to_string(),
+            )
+            .parse()
+            .unwrap(),
+            clock_skew: extract_env("CLOCK_SKEW", default.clock_skew.to_string())
+                .parse()
+                .unwrap(),
+            status_unreachable_policy: extract_env(
+                "STATUS_UNREACHABLE_POLICY",
+                default.status_unreachable_policy.to_string(),
+            )
+            .parse()
+            .unwrap(),
             is_local: extract_env("IS_LOCAL", default.is_local.to_string())
                 .parse()
                 .unwrap(),
             openapi_path: extract_env("OPENAPI_PATH", default.openapi_path),
+            credentials_path: extract_env("CREDENTIALS_PATH", default.credentials_path),
+            credential_registry: default.credential_registry,
             api_version: extract_env("API_VERSION", default.api_version),
         };
+        compound_config.credential_registry = Arc::new(
+            CredentialRegistry::load(&compound_config.credentials_path)
+                .expect("Invalid credential registry"),
+        );
         compound_config
     }
 }
@@ -174,6 +301,9 @@ impl CoreApplicationConfigTrait for CoreApplicationConfig {
     fn get_api_path(&self) -> String {
         format!("/api/{}", self.api_version)
     }
+    fn get_admin_token(&self) -> Option<String> {
+        self.admin_token.clone()
+    }
 }
 
 fn extract_env(env_var_name: &str, default: String) -> String {
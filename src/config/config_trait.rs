@@ -27,4 +27,5 @@ pub trait CoreApplicationConfigTrait: Send + Sync + 'static {
     fn get_weird_port(&self) -> String;
     fn get_openapi_json(&self) -> anyhow::Result<String>;
     fn get_api_path(&self) -> String;
+    fn get_admin_token(&self) -> Option<String>;
 }
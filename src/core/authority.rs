@@ -19,34 +19,41 @@
 
 use crate::config::CoreApplicationConfigTrait;
 use crate::core::traits::{
-    CoreGatekeeperTrait, CoreIssuerTrait, CoreTrait, CoreVcsTrait, CoreVerifierTrait,
-    CoreWalletTrait,
+    CoreGatekeeperTrait, CoreIssuerTrait, CoreLedgerTrait, CoreMinionsTrait, CoreStatusListTrait,
+    CoreTrait, CoreTrustedIssuerTrait, CoreVcsTrait, CoreVerifierTrait, CoreWalletTrait,
 };
 use crate::services::client::ClientServiceTrait;
 use crate::services::gatekeeper::GateKeeperTrait;
 use crate::services::issuer::IssuerTrait;
+use crate::services::notifier::NotifierTrait;
 use crate::services::repo::RepoTrait;
 use crate::services::verifier::VerifierTrait;
 use crate::services::wallet::WalletTrait;
+use crate::types::verifying::VerificationStatus;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 pub struct Core {
     wallet: Arc<dyn WalletTrait>,
     gatekeeper: Arc<dyn GateKeeperTrait>,
     issuer: Arc<dyn IssuerTrait>,
     verifier: Arc<dyn VerifierTrait>,
+    notifier: Arc<dyn NotifierTrait>,
     repo: Arc<dyn RepoTrait>,
     #[allow(dead_code)] // as an orchestrator, it should have access even though it's not used
     client: Arc<dyn ClientServiceTrait>,
     config: Arc<dyn CoreApplicationConfigTrait>,
+    events: broadcast::Sender<VerificationStatus>,
 }
 
 impl Core {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         wallet: Arc<dyn WalletTrait>,
         gatekeeper: Arc<dyn GateKeeperTrait>,
         issuer: Arc<dyn IssuerTrait>,
         verifier: Arc<dyn VerifierTrait>,
+        notifier: Arc<dyn NotifierTrait>,
         repo: Arc<dyn RepoTrait>,
         client: Arc<dyn ClientServiceTrait>,
         config: Arc<dyn CoreApplicationConfigTrait>,
@@ -56,9 +63,11 @@ impl Core {
             gatekeeper,
             issuer,
             verifier,
+            notifier,
             repo,
             client,
             config,
+            events: broadcast::channel(64).0,
         }
     }
 }
@@ -80,6 +89,10 @@ impl CoreVerifierTrait for Core {
     fn repo(&self) -> Arc<dyn RepoTrait> {
         self.repo.clone()
     }
+
+    fn events(&self) -> broadcast::Sender<VerificationStatus> {
+        self.events.clone()
+    }
 }
 
 impl CoreIssuerTrait for Core {
@@ -119,6 +132,10 @@ impl CoreGatekeeperTrait for Core {
         self.issuer.clone()
     }
 
+    fn notifier(&self) -> Arc<dyn NotifierTrait> {
+        self.notifier.clone()
+    }
+
     fn repo(&self) -> Arc<dyn RepoTrait> {
         self.repo.clone()
     }
@@ -133,3 +150,35 @@ impl CoreWalletTrait for Core {
         self.repo.clone()
     }
 }
+
+impl CoreStatusListTrait for Core {
+    fn issuer(&self) -> Arc<dyn IssuerTrait> {
+        self.issuer.clone()
+    }
+
+    fn repo(&self) -> Arc<dyn RepoTrait> {
+        self.repo.clone()
+    }
+}
+
+impl CoreMinionsTrait for Core {
+    fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait> {
+        self.gatekeeper.clone()
+    }
+
+    fn repo(&self) -> Arc<dyn RepoTrait> {
+        self.repo.clone()
+    }
+}
+
+impl CoreLedgerTrait for Core {
+    fn repo(&self) -> Arc<dyn RepoTrait> {
+        self.repo.clone()
+    }
+}
+
+impl CoreTrustedIssuerTrait for Core {
+    fn repo(&self) -> Arc<dyn RepoTrait> {
+        self.repo.clone()
+    }
+}
@@ -18,7 +18,8 @@
  */
 
 use super::{
-    CoreGatekeeperTrait, CoreIssuerTrait, CoreVcsTrait, CoreVerifierTrait, CoreWalletTrait,
+    CoreGatekeeperTrait, CoreIssuerTrait, CoreLedgerTrait, CoreMinionsTrait, CoreStatusListTrait,
+    CoreTrustedIssuerTrait, CoreVcsTrait, CoreVerifierTrait, CoreWalletTrait,
 };
 use crate::config::CoreApplicationConfigTrait;
 use async_trait::async_trait;
@@ -31,6 +32,10 @@ pub trait CoreTrait:
     + CoreVcsTrait
     + CoreGatekeeperTrait
     + CoreWalletTrait
+    + CoreStatusListTrait
+    + CoreMinionsTrait
+    + CoreLedgerTrait
+    + CoreTrustedIssuerTrait
     + Send
     + Sync
     + 'static
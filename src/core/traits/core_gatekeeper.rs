@@ -20,11 +20,12 @@
 use crate::errors::Errors;
 use crate::services::gatekeeper::GateKeeperTrait;
 use crate::services::issuer::IssuerTrait;
+use crate::services::notifier::NotifierTrait;
 use crate::services::repo::RepoTrait;
 use crate::services::verifier::VerifierTrait;
 use crate::types::enums::errors::BadFormat;
-use crate::types::enums::vc_type::VcType;
 use crate::types::gnap::{GrantRequest, GrantResponse, RefBody};
+use crate::types::notifier::TxCodeNotice;
 use anyhow::bail;
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -35,17 +36,23 @@ pub trait CoreGatekeeperTrait: Send + Sync + 'static {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait>;
     fn verifier(&self) -> Arc<dyn VerifierTrait>;
     fn issuer(&self) -> Arc<dyn IssuerTrait>;
+    fn notifier(&self) -> Arc<dyn NotifierTrait>;
     fn repo(&self) -> Arc<dyn RepoTrait>;
     async fn manage_req(&self, payload: GrantRequest) -> anyhow::Result<GrantResponse> {
         let (n_req_mod, n_int_model) = self.gatekeeper().start(payload)?;
-        let _req_model = self.repo().request().create(n_req_mod).await?;
+        let req_model = self.repo().request().create(n_req_mod).await?;
         let int_model = self.repo().interaction().create(n_int_model).await?;
 
         if int_model.start.contains(&"oidc4vp".to_string()) {
-            let n_ver_model = self.verifier().start_vp(&int_model.id, VcType::Identity)?;
+            // Renewals are granted by presenting the credential that is about to expire
+            let vp_type = match req_model.is_renewal {
+                true => req_model.vc_type.as_str(),
+                false => "IdentityCredential",
+            };
+            let n_ver_model = self.verifier().start_vp(&int_model.id, vp_type)?;
             let ver_model = self.repo().verification().create(n_ver_model).await?;
 
-            let uri = self.verifier().generate_verification_uri(ver_model);
+            let uri = self.verifier().generate_verification_uri(ver_model).await?;
 
             let response = GrantResponse::default4oidc4vp(
                 int_model.id,
@@ -80,15 +87,42 @@ pub trait CoreGatekeeperTrait: Send + Sync + 'static {
             .validate_cont_req(&int_model, payload.interact_ref, token)?;
 
         let mut req_model = self.repo().request().get_by_id(&int_model.id).await?;
+        let pending = req_model.clone();
         let vc_uri = self.issuer().generate_issuing_uri(&int_model.id);
 
         req_model.status = "Approved".to_string();
         req_model.vc_uri = Some(vc_uri.clone());
         let req_model = self.repo().request().update(req_model).await?;
 
-        let iss_model = self.issuer().start_vci(&req_model);
-        self.repo().issuing().create(iss_model).await?;
-        info!(vc_uri);
-        Ok(vc_uri)
+        let mut iss_model = self.issuer().start_vci(&req_model);
+        if req_model.is_renewal {
+            let ver_model = self.repo().verification().get_by_id(&req_model.id).await?;
+            iss_model.did = ver_model.holder;
+        }
+        let iss_model = self.repo().issuing().create(iss_model).await?;
+        let offer_uri = self.issuer().get_offer_uri(&iss_model)?;
+        let req_model = match offer_uri != vc_uri {
+            true => {
+                let mut req_model = req_model;
+                req_model.vc_uri = Some(offer_uri.clone());
+                self.repo().request().update(req_model).await?
+            }
+            false => req_model,
+        };
+        let notice = TxCodeNotice {
+            request_id: req_model.id,
+            participant_slug: req_model.participant_slug,
+            contact: req_model.contact,
+            vc_type: req_model.vc_type,
+            tx_code: iss_model.tx_code,
+        };
+        if let Err(e) = self.notifier().send_tx_code(&notice).await {
+            // An offer nobody got the PIN for is useless, the request goes back to pending
+            self.repo().issuing().delete(&iss_model.id).await?;
+            self.repo().request().update(pending).await?;
+            return Err(e);
+        }
+        info!(offer_uri);
+        Ok(offer_uri)
     }
 }
@@ -17,15 +17,22 @@
  *
  */
 
+use crate::data::entities::issuing;
+use crate::errors::{ErrorLogTrait, Errors};
 use crate::services::issuer::IssuerTrait;
 use crate::services::repo::RepoTrait;
 use crate::services::wallet::WalletTrait;
 use crate::types::issuing::{
-    AuthServerMetadata, CredentialRequest, GiveVC, IssuerMetadata, IssuingToken, TokenRequest,
-    VCCredOffer, WellKnownJwks,
+    AuthServerMetadata, CredentialRequest, GiveVC, IssuerMetadata, IssuingToken, NonceResponse,
+    NotificationRequest, TokenRequest, VCCredOffer, WellKnownJwks,
 };
+use crate::types::status_list::STATUS_LIST_SIZE;
+use crate::utils::get_from_opt;
+use anyhow::bail;
 use async_trait::async_trait;
+use serde_json::Value;
 use std::sync::Arc;
+use tracing::error;
 
 #[async_trait]
 pub trait CoreIssuerTrait: Send + Sync + 'static {
@@ -33,38 +40,63 @@ pub trait CoreIssuerTrait: Send + Sync + 'static {
     fn wallet(&self) -> Arc<dyn WalletTrait>;
     fn repo(&self) -> Arc<dyn RepoTrait>;
     async fn get_cred_offer_data(&self, id: String) -> anyhow::Result<VCCredOffer> {
-        let mut model = self.repo().issuing().get_by_id(&id).await?;
-        let data = self.issuer().get_cred_offer_data(&model)?;
-        match model.step {
-            true => {
-                model.step = false;
-                self.repo().issuing().update(model).await?;
-            }
-            false => {}
-        };
-        Ok(data)
+        let model = self.repo().issuing().get_by_id(&id).await?;
+        self.issuer().get_cred_offer_data(&model)
     }
-    fn issuer_metadata(&self) -> IssuerMetadata {
+    fn issuer_metadata(&self) -> anyhow::Result<IssuerMetadata> {
         self.issuer().get_issuer_data()
     }
 
-    fn oauth_server_metadata(&self) -> AuthServerMetadata {
+    fn oauth_server_metadata(&self) -> anyhow::Result<AuthServerMetadata> {
         self.issuer().get_oauth_server_data()
     }
 
-    fn jwks(&self) -> anyhow::Result<WellKnownJwks> {
-        self.wallet().get_jwks_data()
+    fn credential_schema(&self, vc_type: String) -> anyhow::Result<Value> {
+        self.issuer().get_credential_schema(&vc_type)
+    }
+
+    async fn jwks(&self) -> anyhow::Result<WellKnownJwks> {
+        self.issuer().get_jwks().await
+    }
+
+    async fn rotate_keys(&self) -> anyhow::Result<WellKnownJwks> {
+        self.issuer().rotate_keys().await
     }
 
-    async fn get_token(&self, payload: TokenRequest) -> anyhow::Result<IssuingToken> {
-        let model = self
+    async fn get_token(
+        &self,
+        payload: TokenRequest,
+        dpop: Option<String>,
+    ) -> anyhow::Result<IssuingToken> {
+        let mut model = self
             .repo()
             .issuing()
-            .get_by_tx_code(&payload.tx_code)
+            .get_by_pre_auth_code(&payload.pre_authorized_code)
             .await?;
-        self.issuer()
-            .validate_token_req(&model, &payload.tx_code, &payload.pre_authorized_code)?;
-        let response = self.issuer().get_token(&model);
+        let jkt = self
+            .issuer()
+            .validate_dpop(&model, dpop.as_deref(), "token", None)?;
+        let attempts = model.tx_code_attempts;
+        if let Err(e) = self.issuer().validate_token_req(
+            &mut model,
+            payload.tx_code.as_deref(),
+            &payload.pre_authorized_code,
+        ) {
+            // Wrong PINs are counted so the offer cannot be brute forced
+            if model.tx_code_attempts != attempts {
+                self.repo().issuing().update(model).await?;
+            }
+            return Err(e);
+        }
+        let response = self.issuer().get_token(&mut model, &jkt).await?;
+        self.repo().issuing().update(model).await?;
+        Ok(response)
+    }
+
+    async fn get_nonce(&self, token: String) -> anyhow::Result<NonceResponse> {
+        let mut model = self.repo().issuing().get_by_token(&token).await?;
+        let response = self.issuer().refresh_c_nonce(&mut model);
+        self.repo().issuing().update(model).await?;
         Ok(response)
     }
 
@@ -72,17 +104,115 @@ pub trait CoreIssuerTrait: Send + Sync + 'static {
         &self,
         payload: CredentialRequest,
         token: String,
+        dpop: Option<String>,
     ) -> anyhow::Result<GiveVC> {
         let mut iss_model = self.repo().issuing().get_by_token(&token).await?;
+        let jkt =
+            self.issuer()
+                .validate_dpop(&iss_model, dpop.as_deref(), "credential", Some(&token))?;
         self.issuer()
-            .validate_cred_req(&mut iss_model, &payload, &token)?;
-        let did = self.wallet().get_did().await?;
-        let data = self.issuer().issue_cred(&mut iss_model, &did)?;
+            .validate_access_token(&iss_model, &token, &jkt)
+            .await?;
+        let c_nonce = iss_model.c_nonce.clone();
+        if let Err(e) = self
+            .issuer()
+            .validate_cred_req(&mut iss_model, &payload, &token)
+            .await
+        {
+            // A rejected nonce is replaced, the wallet has to retry with the new one
+            if iss_model.c_nonce != c_nonce {
+                self.repo().issuing().update(iss_model).await?;
+            }
+            return Err(e);
+        }
+        let holder = get_from_opt(&iss_model.did, "did")?;
+        if self.repo().minions().is_revoked(&holder).await? {
+            let error = Errors::forbidden_new(&format!("Participant {} has been revoked", holder));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if iss_model.issued_at.is_some() {
+            // Retries get back the credential that was already issued instead of a new one
+            let data = self.issuer().get_issued_cred(&iss_model)?;
+            let iss_model = self.repo().issuing().update(iss_model).await?;
+            self.record_credential(&iss_model).await?;
+            return Ok(data);
+        }
+        let did = self.issuer().get_did().await?;
+        let allocated = match iss_model.status_index {
+            Some(_) => None,
+            None => {
+                let (list_id, index) = self
+                    .repo()
+                    .status_list()
+                    .allocate_index(STATUS_LIST_SIZE)
+                    .await?;
+                iss_model.status_list_id = Some(list_id.clone());
+                iss_model.status_index = Some(index);
+                Some((list_id, index))
+            }
+        };
+        let issued = match self.issuer().issue_cred(&mut iss_model, &did).await {
+            Ok(data) => self
+                .repo()
+                .issuing()
+                .update(iss_model)
+                .await
+                .map(|iss_model| (data, iss_model)),
+            Err(e) => Err(e),
+        };
+        let (data, iss_model) = match issued {
+            Ok(data) => data,
+            Err(e) => {
+                // The index goes back to the list, its bit never belonged to a credential
+                if let Some((list_id, index)) = allocated {
+                    self.repo()
+                        .status_list()
+                        .release_index(&list_id, index)
+                        .await?;
+                }
+                return Err(e);
+            }
+        };
+        self.record_credential(&iss_model).await?;
+        Ok(data)
+    }
+    // Both writes are idempotent, a replayed request completes whatever the first one missed
+    async fn record_credential(&self, iss_model: &issuing::Model) -> anyhow::Result<()> {
         let req_model = self.repo().request().get_by_id(&iss_model.id).await?;
         let int_model = self.repo().interaction().get_by_id(&iss_model.id).await?;
-        let iss_model = self.repo().issuing().update(iss_model).await?;
-        let minion = self.issuer().end(&req_model, &int_model, &iss_model)?;
+        let entry = self.issuer().ledger_entry(iss_model)?;
+        self.repo()
+            .issued_credential()
+            .create_if_missing(entry)
+            .await?;
+        let minion = self.issuer().end(&req_model, &int_model, iss_model)?;
         self.repo().minions().force_create(minion).await?;
-        Ok(data)
+        Ok(())
+    }
+    async fn notify(
+        &self,
+        payload: NotificationRequest,
+        token: String,
+        dpop: Option<String>,
+    ) -> anyhow::Result<()> {
+        let iss_model = self.repo().issuing().get_by_token(&token).await?;
+        let jkt = self.issuer().validate_dpop(
+            &iss_model,
+            dpop.as_deref(),
+            "notification",
+            Some(&token),
+        )?;
+        self.issuer()
+            .validate_access_token(&iss_model, &token, &jkt)
+            .await?;
+        let did = get_from_opt(&iss_model.did, "did")?;
+        let mut req_model = self.repo().request().get_by_id(&iss_model.id).await?;
+        let mut minion = self.repo().minions().get_by_id(&did).await?;
+        self.issuer()
+            .handle_notification(&iss_model, &payload, &mut req_model, &mut minion)?;
+        self.repo().request().update(req_model).await?;
+        self.repo().minions().update(minion).await?;
+        Ok(())
     }
 }
@@ -0,0 +1,92 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::issued_credential;
+use crate::services::repo::RepoTrait;
+use crate::types::enums::export_format::ExportFormat;
+use crate::types::ledger::LedgerQuery;
+use crate::utils::to_csv;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+const LEDGER_COLUMNS: [&str; 12] = [
+    "id",
+    "issuing_id",
+    "vc_type",
+    "subject",
+    "issuer_kid",
+    "valid_from",
+    "valid_until",
+    "status_list_id",
+    "status_index",
+    "digest",
+    "revoked",
+    "suspended",
+];
+
+#[async_trait]
+pub trait CoreLedgerTrait: Send + Sync + 'static {
+    fn repo(&self) -> Arc<dyn RepoTrait>;
+    async fn search_ledger(
+        &self,
+        query: LedgerQuery,
+    ) -> anyhow::Result<Vec<issued_credential::Model>> {
+        self.repo().issued_credential().search(&query).await
+    }
+    async fn get_ledger_entry(&self, id: String) -> anyhow::Result<issued_credential::Model> {
+        self.repo().issued_credential().get_by_id(&id).await
+    }
+    async fn export_ledger(
+        &self,
+        query: LedgerQuery,
+        format: ExportFormat,
+    ) -> anyhow::Result<String> {
+        let entries = self.repo().issued_credential().search(&query).await?;
+        info!("Exporting {} ledger entries as {:?}", entries.len(), format);
+        match format {
+            ExportFormat::Json => Ok(serde_json::to_string_pretty(&entries)?),
+            ExportFormat::Csv => {
+                let rows = entries
+                    .into_iter()
+                    .map(|entry| {
+                        vec![
+                            entry.id,
+                            entry.issuing_id,
+                            entry.vc_type,
+                            entry.subject,
+                            entry.issuer_kid.unwrap_or_default(),
+                            entry.valid_from.to_string(),
+                            entry.valid_until.to_string(),
+                            entry.status_list_id.unwrap_or_default(),
+                            entry
+                                .status_index
+                                .map(|index| index.to_string())
+                                .unwrap_or_default(),
+                            entry.digest,
+                            entry.revoked.to_string(),
+                            entry.suspended.to_string(),
+                        ]
+                    })
+                    .collect();
+                Ok(to_csv(&LEDGER_COLUMNS, rows))
+            }
+        }
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::{minions, revocation};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::gatekeeper::GateKeeperTrait;
+use crate::services::repo::RepoTrait;
+use crate::types::minions::RevocationRequest;
+use crate::types::status_list::StatusPurpose;
+use crate::utils::create_opaque_token;
+use anyhow::bail;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+#[async_trait]
+pub trait CoreMinionsTrait: Send + Sync + 'static {
+    fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait>;
+    fn repo(&self) -> Arc<dyn RepoTrait>;
+    async fn get_all_minions(&self) -> anyhow::Result<Vec<minions::Model>> {
+        self.repo().minions().get_all(None, None).await
+    }
+    async fn get_minion(&self, id: String) -> anyhow::Result<minions::Model> {
+        self.repo().minions().get_by_id(&id).await
+    }
+    async fn get_revocations(&self, id: String) -> anyhow::Result<Vec<revocation::Model>> {
+        self.repo().revocation().get_by_participant(&id).await
+    }
+    async fn revoke_minion(
+        &self,
+        id: String,
+        payload: RevocationRequest,
+    ) -> anyhow::Result<revocation::Model> {
+        let now = chrono::Utc::now().naive_utc();
+        let mut minion = self.repo().minions().get_by_id(&id).await?;
+        if minion.is_revoked {
+            let error = Errors::forbidden_new(&format!("Minion {} is already revoked", id));
+            error!("{}", error.log());
+            bail!(error)
+        }
+
+        // The audit row goes first, a revocation that failed halfway is resumed on it
+        let mut revocation = match self
+            .repo()
+            .revocation()
+            .get_by_participant(&id)
+            .await?
+            .into_iter()
+            .next()
+        {
+            Some(pending) => pending,
+            None => {
+                self.repo()
+                    .revocation()
+                    .create(revocation::NewModel {
+                        participant_id: id.clone(),
+                        reason: payload.reason.clone(),
+                        credentials: Vec::new(),
+                        requests: Vec::new(),
+                        notified: false,
+                    })
+                    .await?
+            }
+        };
+        revocation.reason = payload.reason;
+
+        let mut credentials = Vec::new();
+        let mut requests = Vec::new();
+        for mut iss_model in self.repo().issuing().get_by_did(&id).await? {
+            if let (Some(list_id), Some(index)) =
+                (&iss_model.status_list_id, iss_model.status_index)
+            {
+                self.repo()
+                    .status_list()
+                    .set_status(list_id, StatusPurpose::Revocation, index, true)
+                    .await?;
+                self.repo()
+                    .issued_credential()
+                    .set_status(&iss_model.credential_id, StatusPurpose::Revocation, true)
+                    .await?;
+                credentials.push(iss_model.credential_id.clone());
+            }
+            // Rotating the secrets invalidates any grant or token still in flight
+            iss_model.token = create_opaque_token();
+            iss_model.pre_auth_code = create_opaque_token();
+            iss_model.tx_code = create_opaque_token();
+            let iss_model = self.repo().issuing().update(iss_model).await?;
+
+            let mut int_model = self.repo().interaction().get_by_id(&iss_model.id).await?;
+            int_model.continue_token = create_opaque_token();
+            self.repo().interaction().update(int_model).await?;
+
+            let mut req_model = self.repo().request().get_by_id(&iss_model.id).await?;
+            req_model.status = "Revoked".to_string();
+            req_model.ended_at = Some(now);
+            self.repo().request().update(req_model).await?;
+            requests.push(iss_model.id);
+        }
+        revocation.credentials = credentials;
+        revocation.requests = requests;
+        let mut revocation = self.repo().revocation().update(revocation).await?;
+
+        // Flagging the minion last marks the revocation as complete
+        minion.is_revoked = true;
+        minion.revoked_at = Some(now);
+        let minion = self.repo().minions().update(minion).await?;
+
+        match self
+            .gatekeeper()
+            .notify_revocation(&minion, revocation.reason.clone())
+            .await
+        {
+            Ok(_) => {
+                revocation.notified = true;
+                revocation = self.repo().revocation().update(revocation).await?;
+            }
+            Err(e) => warn!("Unable to notify minion {} of its revocation: {}", id, e),
+        }
+
+        info!(
+            "Minion {} revoked along with {} credentials",
+            revocation.participant_id,
+            revocation.credentials.len()
+        );
+        Ok(revocation)
+    }
+    async fn send_expiry_reminders(&self) -> anyhow::Result<usize> {
+        let now = chrono::Utc::now().naive_utc();
+        let mut sent = 0;
+        for mut iss_model in self.repo().issuing().get_due_reminders(now).await? {
+            let did = match &iss_model.did {
+                Some(data) => data.clone(),
+                None => continue,
+            };
+            let minion = match self.repo().minions().get_by_id(&did).await {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!("Unable to find minion {} to remind: {}", did, e);
+                    continue;
+                }
+            };
+            if minion.is_revoked {
+                continue;
+            }
+            // Failed reminders stay pending and are retried on the next sweep
+            if let Err(e) = self.gatekeeper().notify_expiry(&minion, &iss_model).await {
+                warn!(
+                    "Unable to remind minion {} of its expiring credential: {}",
+                    did, e
+                );
+                continue;
+            }
+            iss_model.reminded_at = Some(now);
+            self.repo().issuing().update(iss_model).await?;
+            sent += 1;
+        }
+        info!("{} expiry reminders sent", sent);
+        Ok(sent)
+    }
+}
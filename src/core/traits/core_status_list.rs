@@ -0,0 +1,63 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::services::issuer::IssuerTrait;
+use crate::services::repo::RepoTrait;
+use crate::types::status_list::{StatusPurpose, StatusUpdate};
+use crate::utils::get_from_opt;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::info;
+
+#[async_trait]
+pub trait CoreStatusListTrait: Send + Sync + 'static {
+    fn issuer(&self) -> Arc<dyn IssuerTrait>;
+    fn repo(&self) -> Arc<dyn RepoTrait>;
+    async fn get_status_list(&self, id: String, purpose: StatusPurpose) -> anyhow::Result<String> {
+        let model = self.repo().status_list().get_by_id(&id).await?;
+        let did = self.issuer().get_did().await?;
+        self.issuer().issue_status_list(&model, purpose, &did).await
+    }
+    async fn update_status(
+        &self,
+        credential_id: String,
+        payload: StatusUpdate,
+    ) -> anyhow::Result<()> {
+        let iss_model = self
+            .repo()
+            .issuing()
+            .get_by_credential_id(&credential_id)
+            .await?;
+        let list_id = get_from_opt(&iss_model.status_list_id, "status_list_id")?;
+        let index = get_from_opt(&iss_model.status_index, "status_index")?;
+        self.repo()
+            .status_list()
+            .set_status(&list_id, payload.purpose, index, payload.status)
+            .await?;
+        self.repo()
+            .issued_credential()
+            .set_status(&credential_id, payload.purpose, payload.status)
+            .await?;
+        info!(
+            "Credential {} {} status set to {}",
+            credential_id, payload.purpose, payload.status
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::trusted_issuer;
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::repo::RepoTrait;
+use crate::types::enums::errors::BadFormat;
+use crate::types::trusted_issuers::TrustedIssuerRequest;
+use anyhow::bail;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{error, info};
+
+#[async_trait]
+pub trait CoreTrustedIssuerTrait: Send + Sync + 'static {
+    fn repo(&self) -> Arc<dyn RepoTrait>;
+    async fn get_all_trusted_issuers(&self) -> anyhow::Result<Vec<trusted_issuer::Model>> {
+        self.repo().trusted_issuer().get_all(None, None).await
+    }
+    async fn get_trusted_issuer(&self, id: String) -> anyhow::Result<trusted_issuer::Model> {
+        self.repo().trusted_issuer().get_by_id(&id).await
+    }
+    async fn add_trusted_issuer(
+        &self,
+        payload: TrustedIssuerRequest,
+    ) -> anyhow::Result<trusted_issuer::Model> {
+        self.validate_trusted_issuer_req(&payload)?;
+        let model = self
+            .repo()
+            .trusted_issuer()
+            .create(trusted_issuer::NewModel {
+                issuer: payload.issuer,
+                vc_types: payload.vc_types,
+                valid_from: payload.valid_from,
+                valid_until: payload.valid_until,
+            })
+            .await?;
+        info!("Issuer {} trusted for {:?}", model.issuer, model.vc_types);
+        Ok(model)
+    }
+    async fn update_trusted_issuer(
+        &self,
+        id: String,
+        payload: TrustedIssuerRequest,
+    ) -> anyhow::Result<trusted_issuer::Model> {
+        self.validate_trusted_issuer_req(&payload)?;
+        let mut model = self.repo().trusted_issuer().get_by_id(&id).await?;
+        model.issuer = payload.issuer;
+        model.vc_types = payload.vc_types;
+        model.valid_from = payload.valid_from;
+        model.valid_until = payload.valid_until;
+        self.repo().trusted_issuer().update(model).await
+    }
+    async fn delete_trusted_issuer(&self, id: String) -> anyhow::Result<()> {
+        self.repo().trusted_issuer().delete(&id).await?;
+        info!("Trusted issuer entry {} removed", id);
+        Ok(())
+    }
+    fn validate_trusted_issuer_req(&self, payload: &TrustedIssuerRequest) -> anyhow::Result<()> {
+        if payload.issuer.is_empty() || payload.vc_types.is_empty() {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                "A trusted issuer needs an issuer and at least one credential type",
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if let (Some(from), Some(until)) = (payload.valid_from, payload.valid_until) {
+            if from >= until {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    "valid_from must be earlier than valid_until",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+        Ok(())
+    }
+}
@@ -17,29 +17,156 @@
  *
  */
 
+use crate::data::entities::{request, verification};
+use crate::errors::{ErrorLogTrait, Errors};
 use crate::services::gatekeeper::GateKeeperTrait;
 use crate::services::repo::RepoTrait;
 use crate::services::verifier::VerifierTrait;
 use crate::types::vcs::VPDef;
+use crate::types::verifying::{VerificationStatus, VerifyPayload};
+use crate::utils::get_from_opt;
+use anyhow::bail;
 use async_trait::async_trait;
 use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::error;
 
 #[async_trait]
 pub trait CoreVerifierTrait: Send + Sync + 'static {
     fn gatekeeper(&self) -> Arc<dyn GateKeeperTrait>;
     fn verifier(&self) -> Arc<dyn VerifierTrait>;
     fn repo(&self) -> Arc<dyn RepoTrait>;
+    fn events(&self) -> broadcast::Sender<VerificationStatus>;
+    // Sessions past their lifetime are closed as expired the first time they are looked up
+    async fn get_session(&self, state: &str) -> anyhow::Result<verification::Model> {
+        let mut ver_model = self.repo().verification().get_by_state(state).await?;
+        if ver_model.status == "Pending" && self.verifier().is_expired(&ver_model) {
+            ver_model.status = "Expired".to_string();
+            ver_model.success = Some(false);
+            ver_model.reason = Some("Verification session has expired".to_string());
+            ver_model.ended_at = Some(chrono::Utc::now().naive_utc());
+            ver_model = self.repo().verification().update(ver_model).await?;
+            let _ = self.events().send(VerificationStatus::from(&ver_model));
+        }
+        Ok(ver_model)
+    }
+    async fn get_pending_session(&self, state: &str) -> anyhow::Result<verification::Model> {
+        let ver_model = self.get_session(state).await?;
+        if ver_model.status != "Pending" {
+            let error = Errors::forbidden_new(&format!(
+                "Verification {} is no longer pending -> {}",
+                state, ver_model.status
+            ));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(ver_model)
+    }
     async fn get_vp_def(&self, state: String) -> anyhow::Result<VPDef> {
-        let ver_model = self.repo().verification().get_by_state(&state).await?;
-        let vpd = self.verifier().generate_vpd(ver_model);
+        let ver_model = self.get_pending_session(&state).await?;
+        let vpd = self.verifier().generate_vpd(ver_model)?;
         Ok(vpd)
     }
-    async fn verify(&self, state: String, vp_token: String) -> anyhow::Result<Option<String>> {
-        let mut ver_model = self.repo().verification().get_by_state(&state).await?;
-        let result = self.verifier().verify_all(&mut ver_model, vp_token);
-        let int_model = self.repo().interaction().get_by_id(&ver_model.id).await?;
+    async fn get_request_object(&self, state: String) -> anyhow::Result<String> {
+        let ver_model = self.get_pending_session(&state).await?;
+        self.verifier().generate_request_object(ver_model).await
+    }
+    async fn get_verification_status(&self, state: String) -> anyhow::Result<VerificationStatus> {
+        let ver_model = self.get_session(&state).await?;
+        Ok(VerificationStatus::from(&ver_model))
+    }
+    async fn verify(
+        &self,
+        state: String,
+        payload: VerifyPayload,
+    ) -> anyhow::Result<Option<String>> {
+        let mut ver_model = self.get_pending_session(&state).await?;
+        self.verifier().claim_state(&ver_model)?;
+        let result = self.run_verification(&mut ver_model, payload).await;
+        // The outcome is recorded whatever happens so pollers and streams can report it
+        ver_model.ended_at = Some(chrono::Utc::now().naive_utc());
+        match &result {
+            Ok(()) => {
+                ver_model.status = "Succeeded".to_string();
+                ver_model.success = Some(true);
+            }
+            Err(e) => {
+                ver_model.status = "Failed".to_string();
+                ver_model.success = Some(false);
+                ver_model.reason = Some(match e.downcast_ref::<Errors>() {
+                    Some(error) => error.cause().to_string(),
+                    None => e.to_string(),
+                });
+            }
+        }
+        let ver_model = self.repo().verification().update(ver_model).await?;
+        let _ = self.events().send(VerificationStatus::from(&ver_model));
         result?;
-        self.repo().verification().update(ver_model).await?;
+        let int_model = self.repo().interaction().get_by_id(&ver_model.id).await?;
         self.gatekeeper().end_verification(int_model).await
     }
+    async fn run_verification(
+        &self,
+        ver_model: &mut verification::Model,
+        payload: VerifyPayload,
+    ) -> anyhow::Result<()> {
+        let (vp_token, presentation_submission) =
+            self.verifier().read_response(ver_model, payload)?;
+        let mut trusted = self.repo().trusted_issuer().get_all(None, None).await?;
+        trusted.extend(self.verifier().own_trusted_issuers().await?);
+        let presented = self
+            .verifier()
+            .verify_all(ver_model, vp_token, presentation_submission, &trusted)
+            .await?;
+        let req_model = self.repo().request().get_by_id(&ver_model.id).await?;
+        if req_model.is_renewal {
+            self.validate_renewal(&req_model, ver_model, &presented)
+                .await?;
+        }
+        Ok(())
+    }
+    async fn validate_renewal(
+        &self,
+        req_model: &request::Model,
+        ver_model: &verification::Model,
+        presented: &[String],
+    ) -> anyhow::Result<()> {
+        let holder = get_from_opt(&ver_model.holder, "holder")?;
+        if self.repo().minions().is_revoked(&holder).await? {
+            let error = Errors::forbidden_new(&format!("Participant {} has been revoked", holder));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        // Only a presented credential issued here to the holder, still valid and in good standing
+        let now = chrono::Utc::now().naive_utc();
+        let mut renewable = false;
+        for iss_model in self.repo().issuing().get_by_did(&holder).await? {
+            if iss_model.vc_type != req_model.vc_type
+                || !presented.contains(&iss_model.credential_id)
+                || iss_model
+                    .expires_at
+                    .is_none_or(|expires_at| expires_at <= now)
+            {
+                continue;
+            }
+            let entry = self
+                .repo()
+                .issued_credential()
+                .get_by_id(&iss_model.credential_id)
+                .await?;
+            if !entry.revoked && !entry.suspended {
+                renewable = true;
+                break;
+            }
+        }
+        if !renewable {
+            let error = Errors::forbidden_new(&format!(
+                "Participant {} has no valid {} to renew",
+                holder, req_model.vc_type
+            ));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(())
+    }
 }
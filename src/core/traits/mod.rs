@@ -20,6 +20,10 @@
 mod core;
 mod core_gatekeeper;
 mod core_issuer;
+mod core_ledger;
+mod core_minions;
+mod core_status_list;
+mod core_trusted_issuer;
 mod core_vcs;
 mod core_verifier;
 mod core_wallet;
@@ -27,6 +31,10 @@ mod core_wallet;
 pub use core::CoreTrait;
 pub use core_gatekeeper::CoreGatekeeperTrait;
 pub use core_issuer::CoreIssuerTrait;
+pub use core_ledger::CoreLedgerTrait;
+pub use core_minions::CoreMinionsTrait;
+pub use core_status_list::CoreStatusListTrait;
+pub use core_trusted_issuer::CoreTrustedIssuerTrait;
 pub use core_vcs::CoreVcsTrait;
 pub use core_verifier::CoreVerifierTrait;
 pub use core_wallet::CoreWalletTrait;
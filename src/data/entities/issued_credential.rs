@@ -0,0 +1,101 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::IntoActiveSet;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "issued_credential")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // CREDENTIAL
+    pub issuing_id: String,                 // ISSUING
+    pub vc_type: String,                    // ISSUING
+    pub subject: String,                    // ISSUING
+    pub issuer_kid: Option<String>,         // CREDENTIAL
+    pub valid_from: chrono::NaiveDateTime,  // CREDENTIAL
+    pub valid_until: chrono::NaiveDateTime, // CREDENTIAL
+    pub status_list_id: Option<String>,     // ISSUING
+    pub status_index: Option<i64>,          // ISSUING
+    pub digest: String,                     // CREDENTIAL
+    pub revoked: bool,                      // STATUS
+    pub suspended: bool,                    // STATUS
+    pub created_at: chrono::NaiveDateTime,  // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub id: String,
+    pub issuing_id: String,
+    pub vc_type: String,
+    pub subject: String,
+    pub issuer_kid: Option<String>,
+    pub valid_from: chrono::NaiveDateTime,
+    pub valid_until: chrono::NaiveDateTime,
+    pub status_list_id: Option<String>,
+    pub status_index: Option<i64>,
+    pub digest: String,
+}
+
+impl IntoActiveSet<ActiveModel> for NewModel {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            issuing_id: ActiveValue::Set(self.issuing_id),
+            vc_type: ActiveValue::Set(self.vc_type),
+            subject: ActiveValue::Set(self.subject),
+            issuer_kid: ActiveValue::Set(self.issuer_kid),
+            valid_from: ActiveValue::Set(self.valid_from),
+            valid_until: ActiveValue::Set(self.valid_until),
+            status_list_id: ActiveValue::Set(self.status_list_id),
+            status_index: ActiveValue::Set(self.status_index),
+            digest: ActiveValue::Set(self.digest),
+            revoked: ActiveValue::Set(false),
+            suspended: ActiveValue::Set(false),
+            created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl IntoActiveSet<ActiveModel> for Model {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            issuing_id: ActiveValue::Set(self.issuing_id),
+            vc_type: ActiveValue::Set(self.vc_type),
+            subject: ActiveValue::Set(self.subject),
+            issuer_kid: ActiveValue::Set(self.issuer_kid),
+            valid_from: ActiveValue::Set(self.valid_from),
+            valid_until: ActiveValue::Set(self.valid_until),
+            status_list_id: ActiveValue::Set(self.status_list_id),
+            status_index: ActiveValue::Set(self.status_index),
+            digest: ActiveValue::Set(self.digest),
+            revoked: ActiveValue::Set(self.revoked),
+            suspended: ActiveValue::Set(self.suspended),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -18,7 +18,8 @@
  */
 
 use super::super::IntoActiveSet;
-use crate::utils::create_opaque_token;
+use crate::types::issuing::TX_CODE_LENGTH;
+use crate::utils::{create_opaque_token, create_pin};
 use sea_orm::entity::prelude::*;
 use sea_orm::ActiveValue;
 
@@ -30,7 +31,7 @@ pub struct Model {
     pub name: String,
     pub pre_auth_code: String,
     pub tx_code: String,
-    pub step: bool,
+    pub tx_code_attempts: i32,
     pub vc_type: String,
     pub uri: String,
     pub token: String,
@@ -38,6 +39,17 @@ pub struct Model {
     pub did: Option<String>,
     pub credential_id: String,
     pub credential: Option<String>,
+    pub status_list_id: Option<String>,
+    pub status_index: Option<i64>,
+    pub c_nonce: Option<String>,
+    pub c_nonce_expires_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+    pub token_issued_at: Option<chrono::NaiveDateTime>,
+    pub issued_at: Option<chrono::NaiveDateTime>,
+    pub notification_id: Option<String>,
+    pub expires_at: Option<chrono::NaiveDateTime>,
+    pub remind_at: Option<chrono::NaiveDateTime>,
+    pub reminded_at: Option<chrono::NaiveDateTime>,
 }
 
 #[derive(Clone, Debug)]
@@ -47,12 +59,13 @@ pub struct NewModel {
     pub vc_type: String,
     pub uri: String,
     pub aud: String,
+    pub did: Option<String>,
 }
 
 impl IntoActiveSet<ActiveModel> for NewModel {
     fn to_active(self) -> ActiveModel {
         let code = create_opaque_token();
-        let tx_code = create_opaque_token();
+        let tx_code = create_pin(TX_CODE_LENGTH);
         let token = create_opaque_token();
         let credential_id = Uuid::new_v4().to_string();
         ActiveModel {
@@ -60,14 +73,25 @@ impl IntoActiveSet<ActiveModel> for NewModel {
             name: ActiveValue::Set(self.name),
             pre_auth_code: ActiveValue::Set(code),
             tx_code: ActiveValue::Set(tx_code),
-            step: ActiveValue::Set(true),
+            tx_code_attempts: ActiveValue::Set(0),
             vc_type: ActiveValue::Set(self.vc_type),
             uri: ActiveValue::Set(self.uri),
             token: ActiveValue::Set(token),
             aud: ActiveValue::Set(self.aud),
-            did: ActiveValue::Set(None),
+            did: ActiveValue::Set(self.did),
             credential_id: ActiveValue::Set(credential_id),
             credential: ActiveValue::Set(None),
+            status_list_id: ActiveValue::Set(None),
+            status_index: ActiveValue::Set(None),
+            c_nonce: ActiveValue::Set(None),
+            c_nonce_expires_at: ActiveValue::Set(None),
+            created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+            token_issued_at: ActiveValue::Set(None),
+            issued_at: ActiveValue::Set(None),
+            notification_id: ActiveValue::Set(None),
+            expires_at: ActiveValue::Set(None),
+            remind_at: ActiveValue::Set(None),
+            reminded_at: ActiveValue::Set(None),
         }
     }
 }
@@ -79,7 +103,7 @@ impl IntoActiveSet<ActiveModel> for Model {
             name: ActiveValue::Set(self.name),
             pre_auth_code: ActiveValue::Set(self.pre_auth_code),
             tx_code: ActiveValue::Set(self.tx_code),
-            step: ActiveValue::Set(self.step),
+            tx_code_attempts: ActiveValue::Set(self.tx_code_attempts),
             vc_type: ActiveValue::Set(self.vc_type),
             uri: ActiveValue::Set(self.uri),
             token: ActiveValue::Set(self.token),
@@ -87,6 +111,17 @@ impl IntoActiveSet<ActiveModel> for Model {
             did: ActiveValue::Set(self.did),
             credential_id: ActiveValue::Set(self.credential_id),
             credential: ActiveValue::Set(self.credential),
+            status_list_id: ActiveValue::Set(self.status_list_id),
+            status_index: ActiveValue::Set(self.status_index),
+            c_nonce: ActiveValue::Set(self.c_nonce),
+            c_nonce_expires_at: ActiveValue::Set(self.c_nonce_expires_at),
+            created_at: ActiveValue::Set(self.created_at),
+            token_issued_at: ActiveValue::Set(self.token_issued_at),
+            issued_at: ActiveValue::Set(self.issued_at),
+            notification_id: ActiveValue::Set(self.notification_id),
+            expires_at: ActiveValue::Set(self.expires_at),
+            remind_at: ActiveValue::Set(self.remind_at),
+            reminded_at: ActiveValue::Set(self.reminded_at),
         }
     }
 }
@@ -28,14 +28,16 @@ use serde::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key)]
     pub participant_id: String, // REQUEST
-    pub participant_slug: String,                // REQUEST
-    pub participant_type: String,                // REQUEST
-    pub base_url: Option<String>,                // REQUEST
-    pub vc_uri: Option<String>,                  // REQUEST
-    pub is_vc_issued: bool,                      // REQUEST
-    pub saved_at: chrono::NaiveDateTime,         // DEFAULT
-    pub last_interaction: chrono::NaiveDateTime, // DEFAULT
-    pub is_me: bool,                             // REQUEST
+    pub participant_slug: String,                  // REQUEST
+    pub participant_type: String,                  // REQUEST
+    pub base_url: Option<String>,                  // REQUEST
+    pub vc_uri: Option<String>,                    // REQUEST
+    pub is_vc_issued: bool,                        // REQUEST
+    pub saved_at: chrono::NaiveDateTime,           // DEFAULT
+    pub last_interaction: chrono::NaiveDateTime,   // DEFAULT
+    pub is_me: bool,                               // REQUEST
+    pub is_revoked: bool,                          // DEFAULT
+    pub revoked_at: Option<chrono::NaiveDateTime>, // REVOCATION
 }
 
 #[derive(Clone, Debug)]
@@ -61,6 +63,8 @@ impl IntoActiveSet<ActiveModel> for NewModel {
             saved_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             last_interaction: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             is_me: ActiveValue::Set(self.is_me),
+            is_revoked: ActiveValue::Set(false),
+            revoked_at: ActiveValue::Set(None),
         }
     }
 }
@@ -77,6 +81,8 @@ impl IntoActiveSet<ActiveModel> for Model {
             saved_at: ActiveValue::Set(self.saved_at),
             last_interaction: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             is_me: ActiveValue::Set(self.is_me),
+            is_revoked: ActiveValue::Set(self.is_revoked),
+            revoked_at: ActiveValue::Set(self.revoked_at),
         }
     }
 }
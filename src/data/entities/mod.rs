@@ -18,7 +18,11 @@
  */
 
 pub mod interaction;
+pub mod issued_credential;
 pub mod issuing;
 pub mod minions;
 pub mod request;
+pub mod revocation;
+pub mod status_list;
+pub mod trusted_issuer;
 pub mod verification;
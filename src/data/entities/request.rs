@@ -31,6 +31,8 @@ pub struct Model {
     pub participant_slug: String,                // REQUEST
     pub vc_type: String,                         // REQUEST
     pub cert: Option<String>,                    // REQUEST
+    pub contact: Option<String>,                 // REQUEST
+    pub is_renewal: bool,                        // REQUEST
     pub vc_uri: Option<String>,                  // RESPONSE
     pub vc_issuing: Option<String>,              // RESPONSE
     pub status: String,                          // DEFAULT
@@ -45,6 +47,8 @@ pub struct NewModel {
     pub participant_slug: String, // REQUEST
     pub vc_type: String,
     pub cert: Option<String>,
+    pub contact: Option<String>,
+    pub is_renewal: bool,
 }
 
 impl IntoActiveSet<ActiveModel> for NewModel {
@@ -54,6 +58,8 @@ impl IntoActiveSet<ActiveModel> for NewModel {
             participant_slug: ActiveValue::Set(self.participant_slug),
             vc_type: ActiveValue::Set(self.vc_type),
             cert: ActiveValue::Set(self.cert),
+            contact: ActiveValue::Set(self.contact),
+            is_renewal: ActiveValue::Set(self.is_renewal),
             vc_uri: ActiveValue::Set(None),
             vc_issuing: ActiveValue::Set(None),
             status: ActiveValue::Set("Pending".to_string()),
@@ -71,6 +77,8 @@ impl IntoActiveSet<ActiveModel> for Model {
             participant_slug: ActiveValue::Set(self.participant_slug),
             vc_type: ActiveValue::Set(self.vc_type),
             cert: ActiveValue::Set(self.cert),
+            contact: ActiveValue::Set(self.contact),
+            is_renewal: ActiveValue::Set(self.is_renewal),
             vc_uri: ActiveValue::Set(self.vc_uri),
             vc_issuing: ActiveValue::Set(self.vc_issuing),
             status: ActiveValue::Set(self.status),
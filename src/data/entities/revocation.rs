@@ -0,0 +1,78 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::IntoActiveSet;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "revocation")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub participant_id: String,            // REQUEST
+    pub reason: Option<String>,            // REQUEST
+    pub credentials: Vec<String>,          // CASCADE
+    pub requests: Vec<String>,             // CASCADE
+    pub notified: bool,                    // CASCADE
+    pub created_at: chrono::NaiveDateTime, // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub participant_id: String,   // REQUEST
+    pub reason: Option<String>,   // REQUEST
+    pub credentials: Vec<String>, // CASCADE
+    pub requests: Vec<String>,    // CASCADE
+    pub notified: bool,           // CASCADE
+}
+
+impl IntoActiveSet<ActiveModel> for NewModel {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(Uuid::new_v4().to_string()),
+            participant_id: ActiveValue::Set(self.participant_id),
+            reason: ActiveValue::Set(self.reason),
+            credentials: ActiveValue::Set(self.credentials),
+            requests: ActiveValue::Set(self.requests),
+            notified: ActiveValue::Set(self.notified),
+            created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl IntoActiveSet<ActiveModel> for Model {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            participant_id: ActiveValue::Set(self.participant_id),
+            reason: ActiveValue::Set(self.reason),
+            credentials: ActiveValue::Set(self.credentials),
+            requests: ActiveValue::Set(self.requests),
+            notified: ActiveValue::Set(self.notified),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,77 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::IntoActiveSet;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "status_list")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub size: i64,                         // REQUEST
+    pub next_index: i64,                   // DEFAULT
+    pub allocated: Vec<u8>,                // DEFAULT
+    pub revocation: Vec<u8>,               // DEFAULT
+    pub suspension: Vec<u8>,               // DEFAULT
+    pub created_at: chrono::NaiveDateTime, // DEFAULT
+    pub updated_at: chrono::NaiveDateTime, // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub size: i64, // REQUEST
+}
+
+impl IntoActiveSet<ActiveModel> for NewModel {
+    fn to_active(self) -> ActiveModel {
+        let bytes = (self.size as usize).div_ceil(8);
+        ActiveModel {
+            id: ActiveValue::Set(Uuid::new_v4().to_string()),
+            size: ActiveValue::Set(self.size),
+            next_index: ActiveValue::Set(0),
+            allocated: ActiveValue::Set(vec![0u8; bytes]),
+            revocation: ActiveValue::Set(vec![0u8; bytes]),
+            suspension: ActiveValue::Set(vec![0u8; bytes]),
+            created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+            updated_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl IntoActiveSet<ActiveModel> for Model {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            size: ActiveValue::Set(self.size),
+            next_index: ActiveValue::Set(self.next_index),
+            allocated: ActiveValue::Set(self.allocated),
+            revocation: ActiveValue::Set(self.revocation),
+            suspension: ActiveValue::Set(self.suspension),
+            created_at: ActiveValue::Set(self.created_at),
+            updated_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
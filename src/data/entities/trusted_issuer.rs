@@ -0,0 +1,74 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::IntoActiveSet;
+use sea_orm::entity::prelude::*;
+use sea_orm::ActiveValue;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "trusted_issuer")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String, // RANDOM
+    pub issuer: String,                             // REQUEST
+    pub vc_types: Vec<String>,                      // REQUEST
+    pub valid_from: Option<chrono::NaiveDateTime>,  // REQUEST
+    pub valid_until: Option<chrono::NaiveDateTime>, // REQUEST
+    pub created_at: chrono::NaiveDateTime,          // DEFAULT
+}
+
+#[derive(Clone, Debug)]
+pub struct NewModel {
+    pub issuer: String,                             // REQUEST
+    pub vc_types: Vec<String>,                      // REQUEST
+    pub valid_from: Option<chrono::NaiveDateTime>,  // REQUEST
+    pub valid_until: Option<chrono::NaiveDateTime>, // REQUEST
+}
+
+impl IntoActiveSet<ActiveModel> for NewModel {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(Uuid::new_v4().to_string()),
+            issuer: ActiveValue::Set(self.issuer),
+            vc_types: ActiveValue::Set(self.vc_types),
+            valid_from: ActiveValue::Set(self.valid_from),
+            valid_until: ActiveValue::Set(self.valid_until),
+            created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
+        }
+    }
+}
+
+impl IntoActiveSet<ActiveModel> for Model {
+    fn to_active(self) -> ActiveModel {
+        ActiveModel {
+            id: ActiveValue::Set(self.id),
+            issuer: ActiveValue::Set(self.issuer),
+            vc_types: ActiveValue::Set(self.vc_types),
+            valid_from: ActiveValue::Set(self.valid_from),
+            valid_until: ActiveValue::Set(self.valid_until),
+            created_at: ActiveValue::Set(self.created_at),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
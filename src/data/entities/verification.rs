@@ -32,9 +32,11 @@ pub struct Model {
     pub nonce: String,                           // RANDOM
     pub vc_type: String,                         // REQUEST
     pub audience: String,                        // SEMI-RANDOM
+    pub response_key: Option<String>,            // RANDOM
     pub holder: Option<String>,                  // RESPONSE
     pub vpt: Option<String>,                     // RESPONSE
     pub success: Option<bool>,                   // RESPONSE
+    pub reason: Option<String>,                  // RESPONSE
     pub status: String,                          // DEFAULT
     pub created_at: chrono::NaiveDateTime,       // DEFAULT
     pub ended_at: Option<chrono::NaiveDateTime>, // RESPONSE
@@ -42,9 +44,10 @@ pub struct Model {
 
 #[derive(Clone, Debug)]
 pub struct NewModel {
-    pub id: String,       // REQUEST
-    pub audience: String, // SEMI-RANDOM
-    pub vc_type: String,  // REQUEST
+    pub id: String,                   // REQUEST
+    pub audience: String,             // SEMI-RANDOM
+    pub vc_type: String,              // REQUEST
+    pub response_key: Option<String>, // RANDOM
 }
 
 impl IntoActiveSet<ActiveModel> for NewModel {
@@ -66,9 +69,11 @@ impl IntoActiveSet<ActiveModel> for NewModel {
             nonce: ActiveValue::Set(nonce),
             vc_type: ActiveValue::Set(self.vc_type),
             audience: ActiveValue::Set(audience),
+            response_key: ActiveValue::Set(self.response_key),
             holder: ActiveValue::Set(None),
             vpt: ActiveValue::Set(None),
             success: ActiveValue::Set(None),
+            reason: ActiveValue::Set(None),
             status: ActiveValue::Set("Pending".to_string()),
             created_at: ActiveValue::Set(chrono::Utc::now().naive_utc()),
             ended_at: ActiveValue::Set(None),
@@ -84,9 +89,11 @@ impl IntoActiveSet<ActiveModel> for Model {
             nonce: ActiveValue::Set(self.nonce),
             vc_type: ActiveValue::Set(self.vc_type),
             audience: ActiveValue::Set(self.audience),
+            response_key: ActiveValue::Set(self.response_key),
             holder: ActiveValue::Set(self.holder),
             vpt: ActiveValue::Set(self.vpt),
             success: ActiveValue::Set(self.success),
+            reason: ActiveValue::Set(self.reason),
             status: ActiveValue::Set(self.status),
             created_at: ActiveValue::Set(self.created_at),
             ended_at: ActiveValue::Set(self.ended_at),
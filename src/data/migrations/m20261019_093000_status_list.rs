@@ -0,0 +1,115 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261019_093000_status_list"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StatusList::Table)
+                    .col(
+                        ColumnDef::new(StatusList::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(StatusList::Size).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(StatusList::NextIndex)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(StatusList::Revocation).binary().not_null())
+                    .col(ColumnDef::new(StatusList::Suspension).binary().not_null())
+                    .col(ColumnDef::new(StatusList::Allocated).binary().not_null())
+                    .col(ColumnDef::new(StatusList::CreatedAt).date_time().not_null())
+                    .col(ColumnDef::new(StatusList::UpdatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Issuing::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Issuing::StatusListId).string())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Issuing::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Issuing::StatusIndex).big_integer())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Issuing::Table)
+                    .drop_column(Issuing::StatusIndex)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Issuing::Table)
+                    .drop_column(Issuing::StatusListId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(StatusList::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum StatusList {
+    Table,
+    Id,
+    Size,
+    NextIndex,
+    Revocation,
+    Suspension,
+    Allocated,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Issuing {
+    Table,
+    StatusListId,
+    StatusIndex,
+}
@@ -0,0 +1,126 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261019_094500_revocation"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Revocation::Table)
+                    .col(
+                        ColumnDef::new(Revocation::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Revocation::ParticipantId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Revocation::Reason).string())
+                    .col(
+                        ColumnDef::new(Revocation::Credentials)
+                            .array(ColumnType::Text)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Revocation::Requests)
+                            .array(ColumnType::Text)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(Revocation::Notified).boolean().not_null())
+                    .col(ColumnDef::new(Revocation::CreatedAt).date_time().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Minions::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(Minions::IsRevoked)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Minions::Table)
+                    .add_column_if_not_exists(ColumnDef::new(Minions::RevokedAt).date_time())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Minions::Table)
+                    .drop_column(Minions::RevokedAt)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Minions::Table)
+                    .drop_column(Minions::IsRevoked)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Revocation::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum Revocation {
+    Table,
+    Id,
+    ParticipantId,
+    Reason,
+    Credentials,
+    Requests,
+    Notified,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Minions {
+    Table,
+    IsRevoked,
+    RevokedAt,
+}
@@ -0,0 +1,110 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use sea_orm_migration::prelude::*;
+
+pub struct Migration;
+impl MigrationName for Migration {
+    fn name(&self) -> &str {
+        "m20261019_101500_issued_credential"
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IssuedCredential::Table)
+                    .col(
+                        ColumnDef::new(IssuedCredential::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(IssuedCredential::IssuingId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(IssuedCredential::VcType).string().not_null())
+                    .col(
+                        ColumnDef::new(IssuedCredential::Subject)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(IssuedCredential::IssuerKid).string())
+                    .col(
+                        ColumnDef::new(IssuedCredential::ValidFrom)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IssuedCredential::ValidUntil)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(IssuedCredential::StatusListId).string())
+                    .col(ColumnDef::new(IssuedCredential::StatusIndex).big_integer())
+                    .col(ColumnDef::new(IssuedCredential::Digest).string().not_null())
+                    .col(
+                        ColumnDef::new(IssuedCredential::Revoked)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IssuedCredential::Suspended)
+                            .boolean()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IssuedCredential::CreatedAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IssuedCredential::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum IssuedCredential {
+    Table,
+    Id,
+    IssuingId,
+    VcType,
+    Subject,
+    IssuerKid,
+    ValidFrom,
+    ValidUntil,
+    StatusListId,
+    StatusIndex,
+    Digest,
+    Revoked,
+    Suspended,
+    CreatedAt,
+}
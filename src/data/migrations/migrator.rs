@@ -19,7 +19,12 @@
 
 use super::{
     m20250403_094651_interaction, m20250403_094651_issuing, m20250403_094651_minions,
-    m20250403_094651_request, m20250403_094651_verification,
+    m20250403_094651_request, m20250403_094651_verification, m20261019_093000_status_list,
+    m20261019_094500_revocation, m20261019_095000_issuing_c_nonce,
+    m20261019_095500_issuing_timestamps, m20261019_100000_tx_code_delivery,
+    m20261019_100500_credential_renewal, m20261019_101000_issuing_notification,
+    m20261019_101500_issued_credential, m20261019_103000_trusted_issuer,
+    m20261019_104500_verification_response_key, m20261019_105500_verification_reason,
 };
 use sea_orm_migration::{MigrationTrait, MigratorTrait};
 
@@ -33,6 +38,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20250403_094651_verification::Migration),
             Box::new(m20250403_094651_issuing::Migration),
             Box::new(m20250403_094651_minions::Migration),
+            Box::new(m20261019_093000_status_list::Migration),
+            Box::new(m20261019_094500_revocation::Migration),
+            Box::new(m20261019_095000_issuing_c_nonce::Migration),
+            Box::new(m20261019_095500_issuing_timestamps::Migration),
+            Box::new(m20261019_100000_tx_code_delivery::Migration),
+            Box::new(m20261019_100500_credential_renewal::Migration),
+            Box::new(m20261019_101000_issuing_notification::Migration),
+            Box::new(m20261019_101500_issued_credential::Migration),
+            Box::new(m20261019_103000_trusted_issuer::Migration),
+            Box::new(m20261019_104500_verification_response_key::Migration),
+            Box::new(m20261019_105500_verification_reason::Migration),
         ]
     }
 }
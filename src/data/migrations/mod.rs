@@ -22,6 +22,17 @@ mod m20250403_094651_issuing;
 mod m20250403_094651_minions;
 mod m20250403_094651_request;
 mod m20250403_094651_verification;
+mod m20261019_093000_status_list;
+mod m20261019_094500_revocation;
+mod m20261019_095000_issuing_c_nonce;
+mod m20261019_095500_issuing_timestamps;
+mod m20261019_100000_tx_code_delivery;
+mod m20261019_100500_credential_renewal;
+mod m20261019_101000_issuing_notification;
+mod m20261019_101500_issued_credential;
+mod m20261019_103000_trusted_issuer;
+mod m20261019_104500_verification_response_key;
+mod m20261019_105500_verification_reason;
 mod migrator;
 
 pub use migrator::Migrator;
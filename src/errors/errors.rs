@@ -19,9 +19,11 @@
 
 use crate::errors::error_log_trait::ErrorLogTrait;
 use crate::types::enums::errors::{BadFormat, MissingAction};
+use axum::http::header::WWW_AUTHENTICATE;
 use axum::response::{IntoResponse, Response};
 use axum::{http::StatusCode, Json};
 use serde::{Deserialize, Serialize};
+use serde_json::json;
 use thiserror::Error;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -122,6 +124,21 @@ pub enum Errors {
         info: ErrorInfo,
         cause: String,
     },
+    #[error("Invalid Proof")]
+    InvalidProofError {
+        #[serde(flatten)]
+        info: ErrorInfo,
+        c_nonce: String,
+        c_nonce_expires_in: i64,
+        cause: String,
+    },
+    #[error("DPoP Error")]
+    DpopError {
+        #[serde(flatten)]
+        info: ErrorInfo,
+        nonce: Option<String>,
+        cause: String,
+    },
     #[error("File Read Error")]
     ReadError {
         #[serde(flatten)]
@@ -308,6 +325,49 @@ impl Errors {
             cause: cause.to_string(),
         }
     }
+    pub fn invalid_proof_new(cause: &str, c_nonce: &str, c_nonce_expires_in: i64) -> Errors {
+        Errors::InvalidProofError {
+            info: ErrorInfo {
+                message: "Invalid proof".to_string(),
+                error_code: 4500,
+                status_code: StatusCode::BAD_REQUEST,
+                details: None,
+            },
+            c_nonce: c_nonce.to_string(),
+            c_nonce_expires_in,
+            cause: cause.to_string(),
+        }
+    }
+
+    // A nonce means the client has to retry the request using it
+    pub fn dpop_new(cause: &str, nonce: Option<String>) -> Errors {
+        Errors::DpopError {
+            info: ErrorInfo {
+                message: "Invalid DPoP proof".to_string(),
+                error_code: 4600,
+                status_code: StatusCode::BAD_REQUEST,
+                details: None,
+            },
+            nonce,
+            cause: cause.to_string(),
+        }
+    }
+
+    // Resource endpoints answer a bad proof with 401, only the token endpoint keeps the 400
+    pub fn into_resource_dpop(self) -> Errors {
+        match self {
+            Errors::DpopError {
+                mut info,
+                nonce,
+                cause,
+            } => {
+                info.status_code = StatusCode::UNAUTHORIZED;
+                Errors::DpopError { info, nonce, cause }
+            }
+            other => other,
+        }
+    }
+
     pub fn read_new(path: &str, cause: &str) -> Self {
         Self::ReadError {
             info: ErrorInfo {
@@ -345,10 +405,67 @@ impl Errors {
             cause: cause.to_string(),
         }
     }
+    pub fn cause(&self) -> &str {
+        match self {
+            Errors::PetitionError { cause, .. }
+            | Errors::ProviderError { cause, .. }
+            | Errors::ConsumerError { cause, .. }
+            | Errors::MissingActionError { cause, .. }
+            | Errors::MissingResourceError { cause, .. }
+            | Errors::FormatError { cause, .. }
+            | Errors::UnauthorizedError { cause, .. }
+            | Errors::ForbiddenError { cause, .. }
+            | Errors::DatabaseError { cause, .. }
+            | Errors::FeatureNotImplError { cause, .. }
+            | Errors::WalletError { cause, .. }
+            | Errors::SecurityError { cause, .. }
+            | Errors::InvalidProofError { cause, .. }
+            | Errors::DpopError { cause, .. }
+            | Errors::ReadError { cause, .. }
+            | Errors::WriteError { cause, .. }
+            | Errors::ParseError { cause, .. } => cause,
+        }
+    }
 }
 
 impl IntoResponse for &Errors {
     fn into_response(self) -> Response {
+        // OpenID4VCI wallets expect the spec error body to pick up the fresh nonce
+        if let Errors::InvalidProofError {
+            info,
+            c_nonce,
+            c_nonce_expires_in,
+            cause,
+        } = self
+        {
+            let body = json!({
+                "error": "invalid_proof",
+                "error_description": cause,
+                "c_nonce": c_nonce,
+                "c_nonce_expires_in": c_nonce_expires_in,
+            });
+            return (info.status_code, Json(body)).into_response();
+        }
+        if let Errors::DpopError { info, nonce, cause } = self {
+            let error = match nonce {
+                Some(_) => "use_dpop_nonce",
+                None => "invalid_dpop_proof",
+            };
+            let body = json!({
+                "error": error,
+                "error_description": cause,
+            });
+            let mut response = (info.status_code, Json(body)).into_response();
+            let headers = response.headers_mut();
+            if let Ok(value) = format!("DPoP error=\"{}\"", error).parse() {
+                headers.insert(WWW_AUTHENTICATE, value);
+            }
+            if let Some(Ok(value)) = nonce.as_ref().map(|nonce| nonce.parse()) {
+                headers.insert("DPoP-Nonce", value);
+            }
+            return response;
+        }
+
         let info = match self {
             Errors::PetitionError { info, .. }
             | Errors::ProviderError { info, .. }
@@ -364,6 +481,8 @@ impl IntoResponse for &Errors {
             | Errors::ParseError { info, .. }
             | Errors::WriteError { info, .. }
             | Errors::SecurityError { info, .. }
+            | Errors::InvalidProofError { info, .. }
+            | Errors::DpopError { info, .. }
             | Errors::WalletError { info, .. } => info,
         };
 
@@ -446,6 +565,14 @@ impl ErrorLogTrait for Errors {
             } => {
                 format!("{}\nResource Id: {}", format_info(info, cause), resource_id)
             }
+            Errors::InvalidProofError {
+                info,
+                c_nonce,
+                cause,
+                ..
+            } => {
+                format!("{}\nFresh c_nonce: {}", format_info(info, cause), c_nonce)
+            }
             Errors::ReadError { info, path, cause } => {
                 format!("{}\nPath: {}", format_info(info, cause), path)
             }
@@ -453,6 +580,7 @@ impl ErrorLogTrait for Errors {
                 format!("{}\nPath: {}", format_info(info, cause), path)
             }
             Errors::FormatError { info, cause }
+            | Errors::DpopError { info, cause, .. }
             | Errors::UnauthorizedError { info, cause }
             | Errors::ForbiddenError { info, cause }
             | Errors::DatabaseError { info, cause }
@@ -0,0 +1,45 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::utils::{extract_bearer_token, sha256_digest};
+use axum::extract::{Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use tracing::error;
+
+// Admin routes only go through with the configured token, or never when none is configured
+pub async fn admin_guard(
+    State(admin_token): State<Option<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let token = extract_bearer_token(request.headers().clone());
+    // Digests are compared so the check does not leak how much of the token matched
+    let authorized = match (admin_token, token) {
+        (Some(expected), Some(token)) => sha256_digest(&expected) == sha256_digest(&token),
+        _ => false,
+    };
+    if !authorized {
+        let error = Errors::unauthorized_new("Admin token is missing or invalid");
+        error!("{}", error.log());
+        return (&error).into_response();
+    }
+    next.run(request).await
+}
@@ -19,16 +19,18 @@
 
 use crate::core::traits::CoreTrait;
 use crate::http::{
-    GateKeeperRouter, IssuerRouter, OpenapiRouter, VcsRouter, VerifierRouter, WalletRouter,
+    admin_guard, GateKeeperRouter, IssuerRouter, LedgerRouter, MinionsRouter, OpenapiRouter,
+    QrRouter, StatusListRouter, TrustedIssuerRouter, VcsRouter, VerifierRouter, WalletRouter,
 };
 use axum::extract::Request;
 use axum::http::StatusCode;
+use axum::middleware::from_fn_with_state;
 use axum::response::IntoResponse;
 use axum::routing::get;
 use axum::Router;
 use std::sync::Arc;
 use tower_http::trace::{DefaultOnResponse, TraceLayer};
-use tracing::{error, info, Level};
+use tracing::{error, info, warn, Level};
 use uuid::Uuid;
 
 pub struct RainbowAuthorityRouter {
@@ -51,7 +53,33 @@ impl RainbowAuthorityRouter {
         let issuer_router = IssuerRouter::new(self.core.clone()).router();
         let verifier_router = VerifierRouter::new(self.core.clone()).router();
         let vcs_router = VcsRouter::new(self.core.clone()).router();
+        let status_list_router = StatusListRouter::new(self.core.clone()).router();
+        let minions_router = MinionsRouter::new(self.core.clone()).router();
+        let trusted_issuer_router = TrustedIssuerRouter::new(self.core.clone()).router();
+        let ledger_router = LedgerRouter::new(self.core.clone()).router();
         let openapi_router = OpenapiRouter::new(self.openapi.clone()).router();
+        let qr_router = QrRouter::new().router();
+
+        let admin_token = self.core.config().get_admin_token();
+        if admin_token.is_none() {
+            warn!("ADMIN_TOKEN is not set, admin routes will reject every request");
+        }
+        let admin_router = Router::new()
+            .nest(
+                "/status-list",
+                StatusListRouter::new(self.core.clone()).admin_router(),
+            )
+            .nest("/minions", minions_router)
+            .nest("/ledger", ledger_router)
+            .nest(
+                "/trusted-issuers",
+                TrustedIssuerRouter::new(self.core.clone()).admin_router(),
+            )
+            .nest(
+                "/issuer",
+                IssuerRouter::new(self.core.clone()).admin_router(),
+            )
+            .route_layer(from_fn_with_state(admin_token, admin_guard));
 
         Router::new()
             .route(
@@ -78,10 +106,26 @@ impl RainbowAuthorityRouter {
                 &format!("{}/verifier", self.core.config().get_api_path()),
                 verifier_router,
             )
+            .nest(
+                &format!("{}/status-list", self.core.config().get_api_path()),
+                status_list_router,
+            )
+            .nest(
+                &format!("{}/trusted-issuers", self.core.config().get_api_path()),
+                trusted_issuer_router,
+            )
+            .nest(
+                &format!("{}/admin", self.core.config().get_api_path()),
+                admin_router,
+            )
             .nest(
                 &format!("{}/docs", self.core.config().get_api_path()),
                 openapi_router,
             )
+            .nest(
+                &format!("{}/qr", self.core.config().get_api_path()),
+                qr_router,
+            )
             .fallback(Self::fallback)
             .layer(
                 TraceLayer::new_for_http()
@@ -20,10 +20,11 @@
 use crate::core::traits::CoreIssuerTrait;
 use crate::errors::{CustomToResponse, ErrorLogTrait, Errors};
 use crate::types::enums::errors::BadFormat;
-use crate::types::issuing::{CredentialRequest, TokenRequest};
-use crate::utils::extract_bearer_token;
+use crate::types::issuing::{CredentialRequest, NotificationRequest, TokenRequest};
+use crate::utils::{extract_dpop_proof, extract_dpop_token};
 use axum::extract::rejection::{FormRejection, JsonRejection};
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
+use axum::http::header::CACHE_CONTROL;
 use axum::http::{HeaderMap, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
@@ -53,8 +54,17 @@ impl IssuerRouter {
                 get(Self::get_oauth_server),
             )
             .route("/jwks", get(Self::get_jwks))
+            .route("/schemas/{vc_type}", get(Self::get_schema))
             .route("/token", post(Self::get_token))
+            .route("/nonce", post(Self::get_nonce))
             .route("/credential", post(Self::post_credential))
+            .route("/notification", post(Self::post_notification))
+            .with_state(self.issuer)
+    }
+
+    pub fn admin_router(self) -> Router {
+        Router::new()
+            .route("/jwks/rotate", post(Self::rotate_keys))
             .with_state(self.issuer)
     }
 
@@ -81,15 +91,38 @@ impl IssuerRouter {
     }
 
     async fn get_issuer(State(issuer): State<Arc<dyn CoreIssuerTrait>>) -> impl IntoResponse {
-        (StatusCode::OK, Json(issuer.issuer_metadata())).into_response()
+        match issuer.issuer_metadata() {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
     }
 
     async fn get_oauth_server(State(issuer): State<Arc<dyn CoreIssuerTrait>>) -> impl IntoResponse {
-        (StatusCode::OK, Json(issuer.oauth_server_metadata())).into_response()
+        match issuer.oauth_server_metadata() {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn get_schema(
+        State(issuer): State<Arc<dyn CoreIssuerTrait>>,
+        Path(vc_type): Path<String>,
+    ) -> impl IntoResponse {
+        match issuer.credential_schema(vc_type) {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
     }
 
     async fn get_jwks(State(issuer): State<Arc<dyn CoreIssuerTrait>>) -> impl IntoResponse {
-        match issuer.jwks() {
+        match issuer.jwks().await {
+            Ok(jwk) => (StatusCode::OK, Json(jwk)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn rotate_keys(State(issuer): State<Arc<dyn CoreIssuerTrait>>) -> impl IntoResponse {
+        match issuer.rotate_keys().await {
             Ok(jwk) => (StatusCode::OK, Json(jwk)).into_response(),
             Err(e) => e.to_response(),
         }
@@ -97,19 +130,40 @@ impl IssuerRouter {
 
     async fn get_token(
         State(issuer): State<Arc<dyn CoreIssuerTrait>>,
+        headers: HeaderMap,
         payload: Result<Form<TokenRequest>, FormRejection>,
     ) -> impl IntoResponse {
         let payload = match payload {
             Ok(Form(data)) => data,
             Err(e) => return e.into_response(),
         };
+        let dpop = extract_dpop_proof(&headers);
 
-        match issuer.get_token(payload).await {
+        match issuer.get_token(payload, dpop).await {
             Ok(data) => (StatusCode::OK, Json(data)).into_response(),
             Err(e) => e.to_response(),
         }
     }
 
+    async fn get_nonce(
+        State(issuer): State<Arc<dyn CoreIssuerTrait>>,
+        headers: HeaderMap,
+    ) -> impl IntoResponse {
+        let token = match extract_dpop_token(&headers) {
+            Some(token) => token,
+            None => {
+                let error = Errors::unauthorized_new("Missing token");
+                error!("{}", error.log());
+                return error.into_response();
+            }
+        };
+
+        match issuer.get_nonce(token).await {
+            Ok(data) => (StatusCode::OK, [(CACHE_CONTROL, "no-store")], Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
     async fn post_credential(
         State(authority): State<Arc<dyn CoreIssuerTrait>>,
         headers: HeaderMap,
@@ -120,7 +174,7 @@ impl IssuerRouter {
             Err(e) => return e.into_response(),
         };
 
-        let token = match extract_bearer_token(headers) {
+        let token = match extract_dpop_token(&headers) {
             Some(token) => token,
             None => {
                 let error = Errors::unauthorized_new("Missing token");
@@ -129,9 +183,37 @@ impl IssuerRouter {
             }
         };
 
-        match authority.get_credential(payload, token).await {
+        let dpop = extract_dpop_proof(&headers);
+
+        match authority.get_credential(payload, token, dpop).await {
             Ok(data) => (StatusCode::OK, Json(data)).into_response(),
             Err(e) => e.to_response(),
         }
     }
+
+    async fn post_notification(
+        State(issuer): State<Arc<dyn CoreIssuerTrait>>,
+        headers: HeaderMap,
+        payload: Result<Json<NotificationRequest>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let payload = match payload {
+            Ok(Json(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        let token = match extract_dpop_token(&headers) {
+            Some(token) => token,
+            None => {
+                let error = Errors::unauthorized_new("Missing token");
+                error!("{}", error.log());
+                return error.into_response();
+            }
+        };
+        let dpop = extract_dpop_proof(&headers);
+
+        match issuer.notify(payload, token, dpop).await {
+            Ok(()) => StatusCode::NO_CONTENT.into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
 }
@@ -0,0 +1,104 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::traits::CoreLedgerTrait;
+use crate::errors::CustomToResponse;
+use crate::types::enums::export_format::ExportFormat;
+use crate::types::ledger::LedgerQuery;
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router};
+use std::sync::Arc;
+
+pub struct LedgerRouter {
+    ledger: Arc<dyn CoreLedgerTrait>,
+}
+
+impl LedgerRouter {
+    pub fn new(ledger: Arc<dyn CoreLedgerTrait>) -> Self {
+        Self { ledger }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/", get(Self::search))
+            .route("/export/{format}", get(Self::export))
+            .route("/{id}", get(Self::get_one))
+            .with_state(self.ledger)
+    }
+
+    async fn search(
+        State(ledger): State<Arc<dyn CoreLedgerTrait>>,
+        query: Result<Query<LedgerQuery>, QueryRejection>,
+    ) -> impl IntoResponse {
+        let query = match query {
+            Ok(Query(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match ledger.search_ledger(query).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn get_one(
+        State(ledger): State<Arc<dyn CoreLedgerTrait>>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        match ledger.get_ledger_entry(id).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn export(
+        State(ledger): State<Arc<dyn CoreLedgerTrait>>,
+        Path(format): Path<ExportFormat>,
+        query: Result<Query<LedgerQuery>, QueryRejection>,
+    ) -> impl IntoResponse {
+        let query = match query {
+            Ok(Query(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match ledger.export_ledger(query, format).await {
+            Ok(data) => {
+                let disposition = format!(
+                    "attachment; filename=\"issued-credentials.{}\"",
+                    format.extension()
+                );
+                (
+                    StatusCode::OK,
+                    [
+                        (CONTENT_TYPE, format.content_type().to_string()),
+                        (CONTENT_DISPOSITION, disposition),
+                    ],
+                    data,
+                )
+                    .into_response()
+            }
+            Err(e) => e.to_response(),
+        }
+    }
+}
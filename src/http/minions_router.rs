@@ -0,0 +1,93 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::traits::CoreMinionsTrait;
+use crate::errors::CustomToResponse;
+use crate::types::minions::RevocationRequest;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::sync::Arc;
+
+pub struct MinionsRouter {
+    minions: Arc<dyn CoreMinionsTrait>,
+}
+
+impl MinionsRouter {
+    pub fn new(minions: Arc<dyn CoreMinionsTrait>) -> Self {
+        Self { minions }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/all", get(Self::get_all_minions))
+            .route("/{id}", get(Self::get_one_minion))
+            .route("/{id}/revoke", post(Self::revoke_minion))
+            .route("/{id}/revocations", get(Self::get_revocations))
+            .with_state(self.minions)
+    }
+
+    async fn get_all_minions(
+        State(minions): State<Arc<dyn CoreMinionsTrait>>,
+    ) -> impl IntoResponse {
+        match minions.get_all_minions().await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn get_one_minion(
+        State(minions): State<Arc<dyn CoreMinionsTrait>>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        match minions.get_minion(id).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn revoke_minion(
+        State(minions): State<Arc<dyn CoreMinionsTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<RevocationRequest>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let payload = match payload {
+            Ok(Json(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match minions.revoke_minion(id, payload).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn get_revocations(
+        State(minions): State<Arc<dyn CoreMinionsTrait>>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        match minions.get_revocations(id).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+}
@@ -17,18 +17,30 @@
  *
  */
 
+mod admin_guard;
 mod core_router;
 mod gatekeeper_router;
 mod issuer_router;
+mod ledger_router;
+mod minions_router;
 mod openapi_router;
+mod qr_router;
+mod status_list_router;
+mod trusted_issuer_router;
 mod vcs_router;
 mod verifier_router;
 mod wallet_router;
 
+pub use admin_guard::admin_guard;
 pub use core_router::RainbowAuthorityRouter;
 pub use gatekeeper_router::GateKeeperRouter;
 pub use issuer_router::IssuerRouter;
+pub use ledger_router::LedgerRouter;
+pub use minions_router::MinionsRouter;
 pub use openapi_router::OpenapiRouter;
+pub use qr_router::QrRouter;
+pub use status_list_router::StatusListRouter;
+pub use trusted_issuer_router::TrustedIssuerRouter;
 pub use vcs_router::VcsRouter;
 pub use verifier_router::VerifierRouter;
 pub use wallet_router::WalletRouter;
@@ -0,0 +1,86 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{CustomToResponse, ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use crate::types::enums::qr_format::QrFormat;
+use crate::utils::render_qr;
+use axum::extract::Query;
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::Router;
+use std::collections::HashMap;
+use tracing::error;
+
+// Only wallet deep links are rendered, this is not a general purpose QR service
+const QR_SCHEMES: [&str; 2] = ["openid-credential-offer://", "openid4vp://"];
+
+#[derive(Default)]
+pub struct QrRouter;
+
+impl QrRouter {
+    pub fn new() -> QrRouter {
+        QrRouter
+    }
+
+    pub fn router(self) -> Router {
+        Router::new().route("/", get(Self::get_qr))
+    }
+
+    async fn get_qr(Query(params): Query<HashMap<String, String>>) -> impl IntoResponse {
+        let uri = match params.get("uri") {
+            Some(data) => data,
+            None => {
+                let error = Errors::format_new(BadFormat::Received, "Missing 'uri' parameter");
+                error!("{}", error.log());
+                return error.into_response();
+            }
+        };
+        if !QR_SCHEMES.iter().any(|scheme| uri.starts_with(scheme)) {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                &format!("Only {} uris can be rendered", QR_SCHEMES.join(" & ")),
+            );
+            error!("{}", error.log());
+            return error.into_response();
+        }
+        let format = match params.get("format") {
+            Some(data) => match data.parse::<QrFormat>() {
+                Ok(data) => data,
+                Err(e) => return e.to_response(),
+            },
+            None => QrFormat::Png,
+        };
+
+        match render_qr(uri, format) {
+            Ok(data) => (
+                StatusCode::OK,
+                [
+                    (CONTENT_TYPE, format.content_type()),
+                    (CACHE_CONTROL, "no-store"),
+                ],
+                data,
+            )
+                .into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::traits::CoreStatusListTrait;
+use crate::errors::CustomToResponse;
+use crate::types::status_list::{StatusPurpose, StatusUpdate};
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct StatusListRouter {
+    status_list: Arc<dyn CoreStatusListTrait>,
+}
+
+impl StatusListRouter {
+    pub fn new(status_list: Arc<dyn CoreStatusListTrait>) -> Self {
+        Self { status_list }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/{id}/{purpose}", get(Self::get_status_list))
+            .with_state(self.status_list)
+    }
+
+    pub fn admin_router(self) -> Router {
+        Router::new()
+            .route("/credential/{id}", post(Self::update_status))
+            .with_state(self.status_list)
+    }
+
+    async fn get_status_list(
+        State(status_list): State<Arc<dyn CoreStatusListTrait>>,
+        Path((id, purpose)): Path<(String, String)>,
+    ) -> impl IntoResponse {
+        let purpose = match StatusPurpose::from_str(&purpose) {
+            Ok(data) => data,
+            Err(e) => return e.to_response(),
+        };
+
+        match status_list.get_status_list(id, purpose).await {
+            Ok(data) => {
+                (StatusCode::OK, [(CONTENT_TYPE, "application/vc+jwt")], data).into_response()
+            }
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn update_status(
+        State(status_list): State<Arc<dyn CoreStatusListTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<StatusUpdate>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let payload = match payload {
+            Ok(Json(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match status_list.update_status(id, payload).await {
+            Ok(_) => StatusCode::OK.into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+}
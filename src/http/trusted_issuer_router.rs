@@ -0,0 +1,113 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::core::traits::CoreTrustedIssuerTrait;
+use crate::errors::CustomToResponse;
+use crate::types::trusted_issuers::TrustedIssuerRequest;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::{get, post, put};
+use axum::{Json, Router};
+use std::sync::Arc;
+
+pub struct TrustedIssuerRouter {
+    trusted_issuer: Arc<dyn CoreTrustedIssuerTrait>,
+}
+
+impl TrustedIssuerRouter {
+    pub fn new(trusted_issuer: Arc<dyn CoreTrustedIssuerTrait>) -> Self {
+        Self { trusted_issuer }
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route("/all", get(Self::get_all))
+            .route("/{id}", get(Self::get_one))
+            .with_state(self.trusted_issuer)
+    }
+
+    pub fn admin_router(self) -> Router {
+        Router::new()
+            .route("/", post(Self::add))
+            .route("/{id}", put(Self::update).delete(Self::delete))
+            .with_state(self.trusted_issuer)
+    }
+
+    async fn get_all(
+        State(trusted_issuer): State<Arc<dyn CoreTrustedIssuerTrait>>,
+    ) -> impl IntoResponse {
+        match trusted_issuer.get_all_trusted_issuers().await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn get_one(
+        State(trusted_issuer): State<Arc<dyn CoreTrustedIssuerTrait>>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        match trusted_issuer.get_trusted_issuer(id).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn add(
+        State(trusted_issuer): State<Arc<dyn CoreTrustedIssuerTrait>>,
+        payload: Result<Json<TrustedIssuerRequest>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let payload = match payload {
+            Ok(Json(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match trusted_issuer.add_trusted_issuer(payload).await {
+            Ok(data) => (StatusCode::CREATED, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn update(
+        State(trusted_issuer): State<Arc<dyn CoreTrustedIssuerTrait>>,
+        Path(id): Path<String>,
+        payload: Result<Json<TrustedIssuerRequest>, JsonRejection>,
+    ) -> impl IntoResponse {
+        let payload = match payload {
+            Ok(Json(data)) => data,
+            Err(e) => return e.into_response(),
+        };
+
+        match trusted_issuer.update_trusted_issuer(id, payload).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn delete(
+        State(trusted_issuer): State<Arc<dyn CoreTrustedIssuerTrait>>,
+        Path(id): Path<String>,
+    ) -> impl IntoResponse {
+        match trusted_issuer.delete_trusted_issuer(id).await {
+            Ok(_) => StatusCode::NO_CONTENT.into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+}
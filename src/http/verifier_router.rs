@@ -19,14 +19,24 @@
 
 use crate::core::traits::CoreVerifierTrait;
 use crate::errors::CustomToResponse;
-use crate::types::verifying::VerifyPayload;
+use crate::types::verifying::{VerificationStatus, VerifyPayload};
 use axum::extract::rejection::FormRejection;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
 use axum::extract::{Path, State};
+use axum::http::header::CONTENT_TYPE;
 use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
 use axum::{Form, Json, Router};
+use futures_util::{stream, Stream, StreamExt};
+use std::pin::pin;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::timeout;
+
+const STATUS_RECHECK: Duration = Duration::from_secs(15);
 
 pub struct VerifierRouter {
     verifier: Arc<dyn CoreVerifierTrait>,
@@ -39,7 +49,11 @@ impl VerifierRouter {
     pub fn router(self) -> Router {
         Router::new()
             .route("/pd/{state}", get(Self::vp_definition))
+            .route("/request/{state}", get(Self::request_object))
             .route("/verify/{state}", post(Self::verify))
+            .route("/status/{state}", get(Self::status))
+            .route("/status/{state}/sse", get(Self::status_sse))
+            .route("/status/{state}/ws", get(Self::status_ws))
             .with_state(self.verifier)
     }
     async fn vp_definition(
@@ -52,6 +66,21 @@ impl VerifierRouter {
         }
     }
 
+    async fn request_object(
+        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        Path(state): Path<String>,
+    ) -> impl IntoResponse {
+        match verifier.get_request_object(state).await {
+            Ok(jwt) => (
+                StatusCode::OK,
+                [(CONTENT_TYPE, "application/oauth-authz-req+jwt")],
+                jwt,
+            )
+                .into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
     async fn verify(
         State(verifier): State<Arc<dyn CoreVerifierTrait>>,
         Path(state): Path<String>,
@@ -62,10 +91,114 @@ impl VerifierRouter {
             Err(e) => return e.into_response(),
         };
 
-        match verifier.verify(state, payload.vp_token).await {
+        match verifier.verify(state, payload).await {
             Ok(Some(uri)) => (StatusCode::OK, uri).into_response(),
             Ok(None) => StatusCode::OK.into_response(),
             Err(e) => e.to_response(),
         }
     }
+
+    async fn status(
+        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        Path(state): Path<String>,
+    ) -> impl IntoResponse {
+        match verifier.get_verification_status(state).await {
+            Ok(data) => (StatusCode::OK, Json(data)).into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn status_sse(
+        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        Path(state): Path<String>,
+    ) -> impl IntoResponse {
+        match Self::status_updates(verifier, state).await {
+            Ok(updates) => {
+                let events =
+                    updates.map(|status| Event::default().event("status").json_data(&status));
+                Sse::new(events)
+                    .keep_alive(KeepAlive::default())
+                    .into_response()
+            }
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn status_ws(
+        State(verifier): State<Arc<dyn CoreVerifierTrait>>,
+        Path(state): Path<String>,
+        ws: WebSocketUpgrade,
+    ) -> impl IntoResponse {
+        match Self::status_updates(verifier, state).await {
+            Ok(updates) => ws
+                .on_upgrade(move |socket| Self::push_status(socket, updates))
+                .into_response(),
+            Err(e) => e.to_response(),
+        }
+    }
+
+    async fn push_status(
+        mut socket: WebSocket,
+        updates: impl Stream<Item = VerificationStatus> + Send + 'static,
+    ) {
+        let mut updates = pin!(updates);
+        loop {
+            tokio::select! {
+                update = updates.next() => {
+                    let Some(status) = update else { break };
+                    let Ok(text) = serde_json::to_string(&status) else { break };
+                    if socket.send(Message::Text(text.into())).await.is_err() {
+                        return;
+                    }
+                }
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                    Some(Ok(_)) => {}
+                }
+            }
+        }
+        let _ = socket.send(Message::Close(None)).await;
+    }
+
+    // Current status followed by every change of the state until it is final
+    async fn status_updates(
+        verifier: Arc<dyn CoreVerifierTrait>,
+        state: String,
+    ) -> anyhow::Result<impl Stream<Item = VerificationStatus> + Send + 'static> {
+        // Subscribing before reading means no change can slip in between
+        let events = verifier.events().subscribe();
+        let current = verifier.get_verification_status(state.clone()).await?;
+        let updates = stream::unfold(
+            (verifier, events, state, Some(current), false),
+            |(verifier, mut events, state, next, done)| async move {
+                if done {
+                    return None;
+                }
+                let status = match next {
+                    Some(status) => status,
+                    None => loop {
+                        // Idle sessions are looked up now and then so their expiry gets reported
+                        match timeout(STATUS_RECHECK, events.recv()).await {
+                            Ok(Ok(status)) if status.state == state => break status,
+                            Ok(Ok(_)) => continue,
+                            Ok(Err(RecvError::Lagged(_))) => {
+                                break verifier.get_verification_status(state.clone()).await.ok()?
+                            }
+                            Ok(Err(RecvError::Closed)) => return None,
+                            Err(_) => {
+                                let status =
+                                    verifier.get_verification_status(state.clone()).await.ok()?;
+                                if status.is_final() {
+                                    break status;
+                                }
+                            }
+                        }
+                    },
+                };
+                let done = status.is_final();
+                Some((status, (verifier, events, state, None, done)))
+            },
+        );
+        Ok(updates)
+    }
 }
@@ -17,7 +17,7 @@
  *
  */
 
-use crate::data::entities::{interaction, request};
+use crate::data::entities::{interaction, issuing, minions, request};
 use crate::types::gnap::{GrantRequest, Interact4GR};
 use crate::types::vcs::VCIData;
 use async_trait::async_trait;
@@ -43,4 +43,14 @@ pub trait GateKeeperTrait: Send + Sync + 'static {
         req_model: &mut request::Model,
         int_model: interaction::Model,
     ) -> anyhow::Result<()>;
+    async fn notify_revocation(
+        &self,
+        minion: &minions::Model,
+        reason: Option<String>,
+    ) -> anyhow::Result<()>;
+    async fn notify_expiry(
+        &self,
+        minion: &minions::Model,
+        iss_model: &issuing::Model,
+    ) -> anyhow::Result<()>;
 }
@@ -19,10 +19,15 @@
 
 use super::GnapConfigTrait;
 use crate::config::{CoreApplicationConfig, CoreApplicationConfigTrait};
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::notifier_type::NotifierType;
 use crate::types::host::HostConfig;
+use std::sync::Arc;
 
 pub struct GnapConfig {
     host: HostConfig,
+    registry: Arc<CredentialRegistry>,
+    notifier_type: NotifierType,
     api_path: String,
 }
 
@@ -31,6 +36,8 @@ impl From<CoreApplicationConfig> for GnapConfig {
         let api_path = config.get_api_path();
         GnapConfig {
             host: config.host,
+            registry: config.credential_registry,
+            notifier_type: config.notifier_type,
             api_path,
         }
     }
@@ -62,4 +69,10 @@ impl GnapConfigTrait for GnapConfig {
     fn get_api_path(&self) -> String {
         self.api_path.clone()
     }
+    fn get_registry(&self) -> Arc<CredentialRegistry> {
+        self.registry.clone()
+    }
+    fn get_notifier_type(&self) -> NotifierType {
+        self.notifier_type
+    }
 }
@@ -17,8 +17,14 @@
  *
  */
 
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::notifier_type::NotifierType;
+use std::sync::Arc;
+
 pub trait GnapConfigTrait {
     fn get_host(&self) -> String;
     fn get_host_without_protocol(&self) -> String;
     fn get_api_path(&self) -> String;
+    fn get_registry(&self) -> Arc<CredentialRegistry>;
+    fn get_notifier_type(&self) -> NotifierType;
 }
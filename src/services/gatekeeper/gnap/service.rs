@@ -18,22 +18,28 @@
  */
 
 use super::config::{GnapConfig, GnapConfigTrait};
-use crate::data::entities::{interaction, request};
+use crate::data::entities::{interaction, issuing, minions, request};
 use crate::errors::{ErrorLogTrait, Errors};
 use crate::services::client::ClientServiceTrait;
 use crate::services::gatekeeper::GateKeeperTrait;
+use crate::services::signer::SignerTrait;
 use crate::types::enums::errors::BadFormat;
+use crate::types::enums::notifier_type::NotifierType;
 use crate::types::enums::request::Body;
-use crate::types::enums::vc_type::VcType;
-use crate::types::gnap::{CallbackBody, GrantRequest, Interact4GR, RejectedCallbackBody};
+use crate::types::gnap::{
+    CallbackBody, ExpiringCallbackBody, GrantRequest, Interact4GR, RejectedCallbackBody,
+    RevokedCallbackBody,
+};
 use crate::types::vcs::VCIData;
-use crate::utils::create_opaque_token;
+use crate::utils::{create_opaque_token, get_from_opt};
 use anyhow::bail;
 use async_trait::async_trait;
 use axum::http::header::{ACCEPT, CONTENT_TYPE};
 use axum::http::HeaderMap;
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
+use jsonwebtoken::Header;
+use serde_json::Value;
 use std::sync::Arc;
 use tracing::{error, info};
 use x509_parser::parse_x509_certificate;
@@ -41,11 +47,44 @@ use x509_parser::parse_x509_certificate;
 pub struct GnapService {
     config: GnapConfig,
     client: Arc<dyn ClientServiceTrait>,
+    signer: Arc<dyn SignerTrait>,
 }
 
 impl GnapService {
-    pub fn new(config: GnapConfig, client: Arc<dyn ClientServiceTrait>) -> Self {
-        GnapService { config, client }
+    pub fn new(
+        config: GnapConfig,
+        client: Arc<dyn ClientServiceTrait>,
+        signer: Arc<dyn SignerTrait>,
+    ) -> Self {
+        GnapService {
+            config,
+            client,
+            signer,
+        }
+    }
+
+    // Callbacks carry a detached JWS of their body so minions can check they come from us
+    async fn callback_headers(&self, body: &Value) -> anyhow::Result<HeaderMap> {
+        let header = Header {
+            typ: Some("gnap-binding-jwsd".to_string()),
+            ..Default::default()
+        };
+        let jws = self.signer.sign(header, body).await?;
+        let detached = match jws.split('.').collect::<Vec<_>>().as_slice() {
+            [protected, _, signature] => format!("{}..{}", protected, signature),
+            _ => {
+                let error =
+                    Errors::format_new(BadFormat::Unknown, "Signer returned a malformed JWS");
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse()?);
+        headers.insert(ACCEPT, "application/json".parse()?);
+        headers.insert("Detached-JWS", detached.parse()?);
+        Ok(headers)
     }
 }
 
@@ -62,13 +101,31 @@ impl GateKeeperTrait for GnapService {
         let client = payload.client;
         let cert = client.key.cert;
         let participant_slug = client.class_id.unwrap_or("Slug".to_string());
+        let contact = payload.subject.as_ref().and_then(|subject| subject.email());
+        // The tx_code is mailed once the grant is approved, there has to be somewhere to send it
+        if contact.is_none() && self.config.get_notifier_type() == NotifierType::Smtp {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                "An email subject identifier is required to receive the tx_code",
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
         let vc_type = payload.access_token.access.r#type.as_str();
-        VcType::from_str(vc_type)?;
+        self.config.get_registry().get(vc_type)?;
+        let is_renewal = payload
+            .access_token
+            .access
+            .actions
+            .as_ref()
+            .is_some_and(|actions| actions.contains(&"renew".to_string()));
         let new_request_model = request::NewModel {
             id: id.clone(),
             participant_slug,
             cert,
+            contact,
             vc_type: vc_type.to_string(),
+            is_renewal,
         };
 
         let host_url = format!(
@@ -117,6 +174,21 @@ impl GateKeeperTrait for GnapService {
             bail!(error);
         }
 
+        let actions = payload
+            .access_token
+            .access
+            .actions
+            .clone()
+            .unwrap_or_default();
+        if actions.contains(&"renew".to_string()) && !start.contains(&"oidc4vp".to_string()) {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                "Renewals can only be requested through oidc4vp",
+            );
+            error!("{}", error.log());
+            bail!(error);
+        }
+
         match interact.finish.uri {
             Some(_) => {}
             None => {
@@ -151,7 +223,12 @@ impl GateKeeperTrait for GnapService {
         let clean = test.strip_prefix("CN=").unwrap_or(test.as_str());
         let website = format!("http://{}", clean.to_string());
         let name = req_model.participant_slug.clone();
-        let vc_type = VcType::from_str(req_model.vc_type.as_str())?;
+        let vc_type = self
+            .config
+            .get_registry()
+            .get(&req_model.vc_type)?
+            .name
+            .clone();
         Ok(VCIData {
             name,
             website,
@@ -198,15 +275,12 @@ impl GateKeeperTrait for GnapService {
         } else if model.method == "push" {
             let url = model.uri;
 
-            let mut headers = HeaderMap::new();
-            headers.insert(CONTENT_TYPE, "application/json".parse()?);
-            headers.insert(ACCEPT, "application/json".parse()?);
-
             let body = CallbackBody {
                 interact_ref: model.interact_ref,
                 hash: model.hash,
             };
             let body = serde_json::to_value(body)?;
+            let headers = self.callback_headers(&body).await?;
             self.client
                 .post(&url, Some(headers), Body::Json(body))
                 .await?;
@@ -248,9 +322,7 @@ impl GateKeeperTrait for GnapService {
             }
         };
 
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, "application/json".parse()?);
-        headers.insert(ACCEPT, "application/json".parse()?);
+        let headers = self.callback_headers(&body).await?;
 
         let res = self
             .client
@@ -276,4 +348,104 @@ impl GateKeeperTrait for GnapService {
 
         Ok(())
     }
+
+    async fn notify_revocation(
+        &self,
+        minion: &minions::Model,
+        reason: Option<String>,
+    ) -> anyhow::Result<()> {
+        info!(
+            "Notifying minion {} of its revocation",
+            minion.participant_id
+        );
+        let base_url = get_from_opt(&minion.base_url, "base_url")?;
+        let body = RevokedCallbackBody {
+            revoked: "Participant was revoked from the dataspace".to_string(),
+            reason,
+            revoked_at: minion
+                .revoked_at
+                .unwrap_or_else(|| chrono::Utc::now().naive_utc()),
+        };
+
+        let body = serde_json::to_value(body)?;
+        let headers = self.callback_headers(&body).await?;
+
+        let res = self
+            .client
+            .post(&base_url, Some(headers), Body::Json(body))
+            .await?;
+
+        match res.status().as_u16() {
+            200 => {
+                info!("Minion received revocation notice successfully");
+                Ok(())
+            }
+            _ => {
+                let error = Errors::consumer_new(
+                    &base_url,
+                    "POST",
+                    Some(res.status().as_u16()),
+                    "Minion did not receive revocation notice successfully",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    async fn notify_expiry(
+        &self,
+        minion: &minions::Model,
+        iss_model: &issuing::Model,
+    ) -> anyhow::Result<()> {
+        info!(
+            "Reminding minion {} that credential {} is about to expire",
+            minion.participant_id, iss_model.credential_id
+        );
+        let base_url = get_from_opt(&minion.base_url, "base_url")?;
+        let expires_at = match iss_model.expires_at {
+            Some(data) => data,
+            None => {
+                let error = Errors::format_new(BadFormat::Unknown, "Credential has no expiry date");
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        let body = ExpiringCallbackBody {
+            expiring: "Credential is about to expire".to_string(),
+            vc_type: iss_model.vc_type.clone(),
+            credential_id: iss_model.credential_id.clone(),
+            expires_at,
+            renewal_endpoint: format!(
+                "{}{}/gate/access",
+                self.config.get_host(),
+                self.config.get_api_path()
+            ),
+        };
+
+        let body = serde_json::to_value(body)?;
+        let headers = self.callback_headers(&body).await?;
+
+        let res = self
+            .client
+            .post(&base_url, Some(headers), Body::Json(body))
+            .await?;
+
+        match res.status().as_u16() {
+            200 => {
+                info!("Minion received expiry reminder successfully");
+                Ok(())
+            }
+            _ => {
+                let error = Errors::consumer_new(
+                    &base_url,
+                    "POST",
+                    Some(res.status().as_u16()),
+                    "Minion did not receive expiry reminder successfully",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
 }
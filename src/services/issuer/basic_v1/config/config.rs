@@ -19,14 +19,19 @@
 
 use crate::config::{CoreApplicationConfig, CoreApplicationConfigTrait};
 use crate::services::issuer::basic_v1::config::config_trait::BasicIssuerConfigTrait;
+use crate::types::credentials::CredentialRegistry;
 use crate::types::host::HostConfig;
 use crate::utils::read;
+use std::sync::Arc;
 
 pub struct BasicIssuerConfig {
     host: HostConfig,
     is_local: bool,
+    offer_by_value: bool,
     keys_path: String,
+    registry: Arc<CredentialRegistry>,
     api_path: String,
+    clock_skew: u64,
 }
 
 impl From<CoreApplicationConfig> for BasicIssuerConfig {
@@ -35,8 +40,11 @@ impl From<CoreApplicationConfig> for BasicIssuerConfig {
         BasicIssuerConfig {
             host: config.host,
             is_local: config.is_local,
+            offer_by_value: config.offer_by_value,
             keys_path: config.keys_path,
+            registry: config.credential_registry,
             api_path,
+            clock_skew: config.clock_skew,
         }
     }
 }
@@ -70,6 +78,10 @@ impl BasicIssuerConfigTrait for BasicIssuerConfig {
         self.is_local
     }
 
+    fn is_offer_by_value(&self) -> bool {
+        self.offer_by_value
+    }
+
     fn get_cert(&self) -> anyhow::Result<String> {
         let path = format!("{}/cert.pem", self.keys_path);
         read(&path)
@@ -85,4 +97,10 @@ impl BasicIssuerConfigTrait for BasicIssuerConfig {
     fn get_api_path(&self) -> String {
         self.api_path.clone()
     }
+    fn get_registry(&self) -> Arc<CredentialRegistry> {
+        self.registry.clone()
+    }
+    fn get_clock_skew(&self) -> u64 {
+        self.clock_skew
+    }
 }
@@ -17,12 +17,18 @@
  *
  */
 
+use crate::types::credentials::CredentialRegistry;
+use std::sync::Arc;
+
 pub trait BasicIssuerConfigTrait {
     fn get_host_without_protocol(&self) -> String;
     fn get_host(&self) -> String;
     fn is_local(&self) -> bool;
+    fn is_offer_by_value(&self) -> bool;
     fn get_cert(&self) -> anyhow::Result<String>;
     fn get_priv_key(&self) -> anyhow::Result<String>;
     fn get_pub_key(&self) -> anyhow::Result<String>;
     fn get_api_path(&self) -> String;
+    fn get_registry(&self) -> Arc<CredentialRegistry>;
+    fn get_clock_skew(&self) -> u64;
 }
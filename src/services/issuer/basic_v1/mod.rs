@@ -20,3 +20,6 @@
 pub mod config;
 mod service;
 pub use service::BasicIssuerService;
+
+#[cfg(test)]
+mod tests;
@@ -19,33 +19,143 @@
 
 use super::super::IssuerTrait;
 use super::config::{BasicIssuerConfig, BasicIssuerConfigTrait};
-use crate::data::entities::{interaction, issuing, minions, request};
+use crate::data::entities::{
+    interaction, issued_credential, issuing, minions, request, status_list,
+};
 use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::resolver::DidResolverTrait;
+use crate::services::signer::SignerTrait;
 use crate::types::enums::errors::BadFormat;
-use crate::types::enums::vc_type::VcType;
+use crate::types::enums::vc_data_model::VcDataModel;
 use crate::types::issuing::{
     AuthServerMetadata, CredentialRequest, DidPossession, GiveVC, IssuerMetadata, IssuingToken,
-    VCCredOffer,
+    NonceResponse, NotificationEvent, NotificationRequest, VCCredOffer, WellKnownJwks,
+    ACCESS_TOKEN_TTL, C_NONCE_TTL, MAX_TX_CODE_ATTEMPTS, PRE_AUTH_CODE_TTL,
+};
+use crate::types::jwt::{AccessTokenClaims, Confirmation, DPOP_NONCE_TTL, DPOP_PROOF_TTL};
+use crate::types::status_list::{StatusPurpose, STATUS_LIST_TTL};
+use crate::types::vcs::{
+    BitstringStatusListEntry, CredentialSchema, VCClaimsV1, VCClaimsV2, VCFromClaimsV1, VCIssuer,
+};
+use crate::utils::{
+    create_opaque_token, encode_status_list, get_from_opt, is_active, sha256_digest, trim_4_base,
+    validate_dpop_proof, validate_token, TtlSet,
 };
-use crate::types::vcs::cred_subject::{CredentialSubject4DataSpace, CredentialSubject4Identity};
-use crate::types::vcs::{VCClaimsV1, VCFromClaimsV1, VCIssuer};
-use crate::utils::{get_from_opt, has_expired, is_active, trim_4_base, validate_token};
 use anyhow::bail;
+use async_trait::async_trait;
 use chrono::{Duration, Utc};
-use jsonwebtoken::{encode, Algorithm, EncodingKey, Header, TokenData};
+use jsonwebtoken::{decode, DecodingKey, Header, TokenData, Validation};
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::sync::Arc;
 use tracing::{error, info};
 use urlencoding;
 
 pub struct BasicIssuerService {
     config: BasicIssuerConfig,
+    signer: Arc<dyn SignerTrait>,
+    resolver: Arc<dyn DidResolverTrait>,
+    dpop_nonces: TtlSet,
+    dpop_jtis: TtlSet,
 }
 
 impl BasicIssuerService {
-    pub fn new(config: BasicIssuerConfig) -> BasicIssuerService {
-        BasicIssuerService { config }
+    pub fn new(
+        config: BasicIssuerConfig,
+        signer: Arc<dyn SignerTrait>,
+        resolver: Arc<dyn DidResolverTrait>,
+    ) -> BasicIssuerService {
+        BasicIssuerService {
+            config,
+            signer,
+            resolver,
+            dpop_nonces: TtlSet::new(),
+            dpop_jtis: TtlSet::new(),
+        }
+    }
+
+    fn offer_hosts(&self) -> (String, String) {
+        let semi_host = format!(
+            "{}{}/issuer",
+            self.config.get_host_without_protocol(),
+            self.config.get_api_path()
+        );
+        let host = format!(
+            "{}{}/issuer",
+            self.config.get_host(),
+            self.config.get_api_path()
+        );
+        match self.config.is_local() {
+            true => {
+                let a = semi_host.replace("127.0.0.1", "host.docker.internal");
+                let b = host.replace("127.0.0.1", "host.docker.internal");
+                (a, b)
+            }
+            false => (semi_host, host),
+        }
+    }
+
+    async fn sign<T: Serialize + Sync>(
+        &self,
+        claims: &T,
+        did: &str,
+        typ: &str,
+    ) -> anyhow::Result<String> {
+        let header = Header {
+            typ: Some(typ.to_string()),
+            kid: Some(did.to_string()),
+            ..Default::default()
+        };
+        self.signer
+            .sign(header, &serde_json::to_value(claims)?)
+            .await
+    }
+
+    fn check_dpop(
+        &self,
+        model: &issuing::Model,
+        proof: Option<&str>,
+        endpoint: &str,
+        access_token: Option<&str>,
+    ) -> anyhow::Result<String> {
+        info!("Validating DPoP");
+
+        let proof = match proof {
+            Some(data) => data,
+            None => {
+                let error = Errors::dpop_new("Missing DPoP proof", None);
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        let htu = format!("{}/{}", model.aud, endpoint);
+        let (claims, jkt) = validate_dpop_proof(proof, "POST", &htu, access_token)?;
+
+        let known_nonce = claims
+            .nonce
+            .as_deref()
+            .is_some_and(|nonce| self.dpop_nonces.contains(nonce));
+        if !known_nonce {
+            let nonce = create_opaque_token();
+            self.dpop_nonces.insert(&nonce, DPOP_NONCE_TTL);
+            let error = Errors::dpop_new(
+                "DPoP proof must use the nonce given by the issuer",
+                Some(nonce),
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+        // jtis only need to be remembered while their proof is fresh
+        if !self.dpop_jtis.insert(&claims.jti, 2 * DPOP_PROOF_TTL) {
+            let error = Errors::dpop_new("DPoP proof has already been used", None);
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(jkt)
     }
 }
 
+#[async_trait]
 impl IssuerTrait for BasicIssuerService {
     fn start_vci(&self, model: &request::Model) -> issuing::NewModel {
         info!("Starting OIDC4VCI");
@@ -66,28 +176,12 @@ impl IssuerTrait for BasicIssuerService {
             vc_type: model.vc_type.clone(),
             uri,
             aud,
+            did: None,
         }
     }
 
     fn generate_issuing_uri(&self, id: &str) -> String {
-        let semi_host = format!(
-            "{}{}/issuer",
-            self.config.get_host_without_protocol(),
-            self.config.get_api_path()
-        );
-        let host = format!(
-            "{}{}/issuer",
-            self.config.get_host(),
-            self.config.get_api_path()
-        );
-        let (semi_host, host) = match self.config.is_local() {
-            true => {
-                let a = semi_host.replace("127.0.0.1", "host.docker.internal");
-                let b = host.replace("127.0.0.1", "host.docker.internal");
-                (a, b)
-            }
-            false => (semi_host, host),
-        };
+        let (semi_host, host) = self.offer_hosts();
         let h_host = format!("{}/credentialOffer?id={}", host, &id);
         let encoded_host = urlencoding::encode(h_host.as_str());
         let uri = format!(
@@ -98,6 +192,21 @@ impl IssuerTrait for BasicIssuerService {
         uri
     }
 
+    fn get_offer_uri(&self, model: &issuing::Model) -> anyhow::Result<String> {
+        if !self.config.is_offer_by_value() {
+            return Ok(model.uri.clone());
+        }
+        info!("Embedding credential offer by value");
+
+        let (semi_host, _) = self.offer_hosts();
+        let offer = serde_json::to_string(&self.get_cred_offer_data(model)?)?;
+        let encoded_offer = urlencoding::encode(offer.as_str());
+        Ok(format!(
+            "openid-credential-offer://{}/?credential_offer={}",
+            semi_host, encoded_offer
+        ))
+    }
+
     fn get_cred_offer_data(&self, model: &issuing::Model) -> anyhow::Result<VCCredOffer> {
         info!("Retrieving credential offer data");
 
@@ -111,17 +220,16 @@ impl IssuerTrait for BasicIssuerService {
             false => issuer,
         };
 
-        let vc_type = VcType::from_str(&model.vc_type)?;
-
-        let offer = match model.step {
-            true => VCCredOffer::new(issuer, model.tx_code.clone(), vc_type),
-            false => VCCredOffer::new(issuer, model.pre_auth_code.clone(), vc_type),
-        };
+        let conf_id = self.config.get_registry().get(&model.vc_type)?.conf_id();
 
-        Ok(offer)
+        Ok(VCCredOffer::new(
+            issuer,
+            model.pre_auth_code.clone(),
+            conf_id,
+        ))
     }
 
-    fn get_issuer_data(&self) -> IssuerMetadata {
+    fn get_issuer_data(&self) -> anyhow::Result<IssuerMetadata> {
         info!("Retrieving issuer data");
         let host = format!(
             "{}{}/issuer",
@@ -132,10 +240,11 @@ impl IssuerTrait for BasicIssuerService {
             true => host.replace("127.0.0.1", "host.docker.internal"),
             false => host,
         };
-        IssuerMetadata::new(&host)
+        let registry = self.config.get_registry();
+        Ok(IssuerMetadata::new(&host, &registry))
     }
 
-    fn get_oauth_server_data(&self) -> AuthServerMetadata {
+    fn get_oauth_server_data(&self) -> anyhow::Result<AuthServerMetadata> {
         info!("Retrieving oauth server data");
 
         let host = format!(
@@ -148,28 +257,154 @@ impl IssuerTrait for BasicIssuerService {
             false => host,
         };
 
-        AuthServerMetadata::new(&host)
+        let registry = self.config.get_registry();
+        Ok(AuthServerMetadata::new(&host, &registry))
     }
 
-    fn get_token(&self, model: &issuing::Model) -> IssuingToken {
+    async fn get_jwks(&self) -> anyhow::Result<WellKnownJwks> {
+        info!("Retrieving jwks data");
+        let keys = self.signer.get_jwks().await?;
+        Ok(WellKnownJwks::new(keys))
+    }
+
+    async fn rotate_keys(&self) -> anyhow::Result<WellKnownJwks> {
+        info!("Rotating signing keys");
+        let keys = self.signer.rotate().await?;
+        Ok(WellKnownJwks::new(keys))
+    }
+
+    async fn get_did(&self) -> anyhow::Result<String> {
+        self.signer.get_did().await
+    }
+
+    async fn get_token(
+        &self,
+        model: &mut issuing::Model,
+        jkt: &str,
+    ) -> anyhow::Result<IssuingToken> {
         info!("Giving token");
-        IssuingToken::new(model.token.clone())
+
+        let now = Utc::now();
+        let claims = AccessTokenClaims {
+            iss: model.aud.clone(),
+            sub: model.id.clone(),
+            aud: model.aud.clone(),
+            iat: now.timestamp(),
+            exp: (now + Duration::seconds(ACCESS_TOKEN_TTL)).timestamp(),
+            jti: uuid::Uuid::new_v4().to_string(),
+            cnf: Confirmation {
+                jkt: jkt.to_string(),
+            },
+        };
+        let header = Header {
+            typ: Some("at+jwt".to_string()),
+            ..Default::default()
+        };
+        let token = self
+            .signer
+            .sign(header, &serde_json::to_value(claims)?)
+            .await?;
+
+        model.token = token.clone();
+        model.token_issued_at = Some(now.naive_utc());
+        let nonce = self.refresh_c_nonce(model);
+        Ok(IssuingToken::new(token, nonce))
     }
-    fn validate_token_req(
+
+    async fn validate_access_token(
         &self,
         model: &issuing::Model,
-        tx_code: &str,
+        token: &str,
+        jkt: &str,
+    ) -> anyhow::Result<()> {
+        info!("Validating access token");
+
+        let header = jsonwebtoken::decode_header(token)?;
+        let keys = self.signer.get_jwks().await?;
+        let jwk = match keys.iter().find(|jwk| jwk.common.key_id == header.kid) {
+            Some(data) => data,
+            None => {
+                let error = Errors::unauthorized_new("Access token was not signed by this issuer");
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        let mut val = Validation::new(header.alg);
+        val.set_audience(&[&model.aud]);
+        val.set_issuer(&[&model.aud]);
+        let claims = match decode::<AccessTokenClaims>(token, &DecodingKey::from_jwk(jwk)?, &val) {
+            Ok(data) => data.claims,
+            Err(e) => {
+                let error = Errors::unauthorized_new(&format!("Invalid access token -> {}", e));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        if claims.sub != model.id || claims.cnf.jkt != jkt {
+            let error = Errors::unauthorized_new("Access token is not bound to this DPoP key");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(())
+    }
+
+    fn validate_dpop(
+        &self,
+        model: &issuing::Model,
+        proof: Option<&str>,
+        endpoint: &str,
+        access_token: Option<&str>,
+    ) -> anyhow::Result<String> {
+        let result = self.check_dpop(model, proof, endpoint, access_token);
+        match endpoint {
+            "token" => result,
+            _ => result.map_err(|e| match e.downcast::<Errors>() {
+                Ok(error) => error.into_resource_dpop().into(),
+                Err(e) => e,
+            }),
+        }
+    }
+
+    fn refresh_c_nonce(&self, model: &mut issuing::Model) -> NonceResponse {
+        info!("Generating c_nonce");
+        let c_nonce = create_opaque_token();
+        model.c_nonce = Some(c_nonce.clone());
+        model.c_nonce_expires_at = Some(Utc::now().naive_utc() + Duration::seconds(C_NONCE_TTL));
+        NonceResponse::new(c_nonce, C_NONCE_TTL)
+    }
+    fn validate_token_req(
+        &self,
+        model: &mut issuing::Model,
+        tx_code: Option<&str>,
         pre_auth_code: &str,
     ) -> anyhow::Result<()> {
         info!("Validating token request");
 
-        if model.tx_code != tx_code {
+        if model.pre_auth_code != pre_auth_code {
+            let error = Errors::forbidden_new("pre_auth_code does not match");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if model.tx_code_attempts >= MAX_TX_CODE_ATTEMPTS {
+            let error = Errors::forbidden_new("Too many wrong tx_codes, the offer is locked");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if tx_code != Some(model.tx_code.as_str()) {
+            model.tx_code_attempts += 1;
             let error = Errors::forbidden_new("tx_code does not match");
             error!("{}", error.log());
             bail!(error)
         }
-        if model.pre_auth_code != pre_auth_code {
-            let error = Errors::forbidden_new("pre_auth_code does not match");
+        if model.token_issued_at.is_some() {
+            let error = Errors::forbidden_new("pre_auth_code has already been redeemed");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if model.created_at + Duration::seconds(PRE_AUTH_CODE_TTL) < Utc::now().naive_utc() {
+            let error = Errors::forbidden_new("pre_auth_code has expired");
             error!("{}", error.log());
             bail!(error)
         }
@@ -177,95 +412,203 @@ impl IssuerTrait for BasicIssuerService {
         Ok(())
     }
 
-    fn issue_cred(&self, model: &mut issuing::Model, did: &str) -> anyhow::Result<GiveVC> {
+    async fn issue_cred(&self, model: &mut issuing::Model, did: &str) -> anyhow::Result<GiveVC> {
         info!("Issuing cred");
 
-        let credential_subject = match VcType::from_str(&model.vc_type)? {
-            VcType::DataSpaceParticipant => {
-                serde_json::to_value(CredentialSubject4DataSpace::new(
-                    get_from_opt(&model.did, "did")?,
-                    model.name.clone(),
-                ))?
+        let registry = self.config.get_registry();
+        let cred_type = registry.get(&model.vc_type)?;
+        let holder = get_from_opt(&model.did, "did")?;
+        let credential_subject = cred_type.credential_subject(&holder, &model.name);
+        cred_type.validate_subject(&credential_subject)?;
+        let credential_schema = cred_type
+            .schema
+            .as_ref()
+            .map(|_| CredentialSchema::new(&self.get_schema_uri(&cred_type.name)));
+
+        let credential_status = match (&model.status_list_id, model.status_index) {
+            (Some(list_id), Some(index)) => {
+                let revocation = BitstringStatusListEntry::new(
+                    &self.get_status_list_uri(list_id, StatusPurpose::Revocation),
+                    index,
+                    StatusPurpose::Revocation,
+                );
+                let suspension = BitstringStatusListEntry::new(
+                    &self.get_status_list_uri(list_id, StatusPurpose::Suspension),
+                    index,
+                    StatusPurpose::Suspension,
+                );
+                Some(serde_json::to_value(vec![revocation, suspension])?)
             }
-            VcType::Identity => serde_json::to_value(CredentialSubject4Identity::new(
-                get_from_opt(&model.did, "did")?,
-                model.name.clone(),
-            ))?,
+            _ => None,
         };
 
         let now = Utc::now();
-        let claims = VCClaimsV1 {
-            exp: None,
-            iat: None,
-            iss: None,
-            sub: None,
-            vc: VCFromClaimsV1 {
-                context: vec!["https://www.w3.org/ns/credentials/v2".to_string()],
-                r#type: vec!["VerifiableCredential".to_string(), model.vc_type.clone()],
+        let valid_until = now + Duration::days(cred_type.validity_days);
+        let data_model = cred_type.data_model;
+        let context = vec![data_model.context().to_string()];
+        let r#type = vec!["VerifiableCredential".to_string(), model.vc_type.clone()];
+        let issuer = VCIssuer {
+            id: did.to_string(),
+            name: "RainbowAuthority".to_string(),
+        };
+        let claims = match data_model {
+            // VC-JWT 1.1 mirrors the registered claims inside the "vc" envelope
+            VcDataModel::V1 => serde_json::to_value(VCClaimsV1 {
+                exp: Some(valid_until.timestamp() as usize),
+                iat: Some(now.timestamp() as usize),
+                nbf: Some(now.timestamp() as usize),
+                jti: Some(model.credential_id.clone()),
+                iss: Some(did.to_string()),
+                sub: Some(holder),
+                vc: VCFromClaimsV1 {
+                    context,
+                    r#type,
+                    id: model.credential_id.clone(),
+                    credential_subject,
+                    issuer,
+                    issuance_date: now,
+                    expiration_date: Some(valid_until),
+                    credential_status,
+                    credential_schema,
+                },
+            })?,
+            // VCDM 2.0 carries them next to the credential so JOSE checks still apply
+            VcDataModel::V2 => serde_json::to_value(VCClaimsV2 {
+                exp: Some(valid_until.timestamp() as usize),
+                iat: Some(now.timestamp() as usize),
+                nbf: Some(now.timestamp() as usize),
+                iss: Some(did.to_string()),
+                sub: Some(holder),
+                context,
+                r#type,
                 id: model.credential_id.clone(),
                 credential_subject,
-                issuer: VCIssuer {
-                    id: did.to_string(),
-                    name: "RainbowAuthority".to_string(),
-                },
+                issuer,
                 valid_from: Some(now),
-                valid_until: Some(now + Duration::days(365)),
-            },
+                valid_until: Some(valid_until),
+                credential_status,
+                credential_schema,
+            })?,
         };
-        // let claims = VCClaimsV2 {
-        //     exp: None,
-        //     iat: None,
-        //     iss: None,
-        //     sub: None,
-        //     context: vec!["https://www.w3.org/ns/credentials/v2".to_string()],
-        //     r#type: vec!["VerifiableCredential".to_string(), model.vc_type.clone()],
-        //     id: model.credential_id.clone(),
-        //     credential_subject,
-        //     issuer: VCIssuer {
-        //         id: did.to_string(),
-        //         name: "RainbowAuthority".to_string(),
-        //     },
-        //     valid_from: Some(now),
-        //     valid_until: Some(now + Duration::days(365)),
-        // };
-
-        let mut header = Header::new(Algorithm::RS256);
-        header.kid = Some(did.to_string());
-
-        let data = self.config.get_priv_key()?;
-        println!("{}", data);
-        let key = match EncodingKey::from_rsa_pem(self.config.get_priv_key()?.as_bytes()) {
-            Ok(data) => data,
-            Err(e) => {
-                let error = Errors::format_new(
-                    BadFormat::Unknown,
-                    &format!("Error parsing private key: {}", e.to_string()),
-                );
-                error!("{}", error.log());
-                bail!(error)
-            }
+
+        let vc_jwt = self.sign(&claims, did, data_model.typ()).await?;
+
+        model.credential = Some(vc_jwt.clone());
+        model.issued_at = Some(now.naive_utc());
+        model.expires_at = Some(valid_until.naive_utc());
+        model.remind_at = Some((valid_until - Duration::days(cred_type.reminder_days)).naive_utc());
+        model.reminded_at = None;
+        model.notification_id = Some(uuid::Uuid::new_v4().to_string());
+        Ok(GiveVC {
+            format: cred_type.format.clone(),
+            credential: vc_jwt,
+            notification_id: model.notification_id.clone(),
+        })
+    }
+
+    fn get_issued_cred(&self, model: &issuing::Model) -> anyhow::Result<GiveVC> {
+        info!("Returning already issued cred");
+
+        let registry = self.config.get_registry();
+        let cred_type = registry.get(&model.vc_type)?;
+        Ok(GiveVC {
+            format: cred_type.format.clone(),
+            credential: get_from_opt(&model.credential, "credential")?,
+            notification_id: model.notification_id.clone(),
+        })
+    }
+
+    fn get_status_list_uri(&self, id: &str, purpose: StatusPurpose) -> String {
+        let host = format!(
+            "{}{}/status-list",
+            self.config.get_host(),
+            self.config.get_api_path()
+        );
+        let host = match self.config.is_local() {
+            true => host.replace("127.0.0.1", "host.docker.internal"),
+            false => host,
         };
+        format!("{}/{}/{}", host, id, purpose)
+    }
 
-        let vc_jwt = match encode(&header, &claims, &key) {
-            Ok(data) => data,
-            Err(e) => {
-                let error = Errors::format_new(
-                    BadFormat::Unknown,
-                    &format!("Error parsing private key: {}", e.to_string()),
+    fn get_schema_uri(&self, vc_type: &str) -> String {
+        let host = format!(
+            "{}{}/issuer",
+            self.config.get_host(),
+            self.config.get_api_path()
+        );
+        let host = match self.config.is_local() {
+            true => host.replace("127.0.0.1", "host.docker.internal"),
+            false => host,
+        };
+        format!("{}/schemas/{}", host, vc_type)
+    }
+
+    fn get_credential_schema(&self, vc_type: &str) -> anyhow::Result<Value> {
+        info!("Retrieving {} schema", vc_type);
+        let registry = self.config.get_registry();
+        match &registry.get(vc_type)?.schema {
+            Some(schema) => Ok(schema.clone()),
+            None => {
+                let error = Errors::missing_resource_new(
+                    vc_type,
+                    &format!("No schema registered for {}", vc_type),
                 );
                 error!("{}", error.log());
                 bail!(error)
             }
+        }
+    }
+
+    async fn issue_status_list(
+        &self,
+        model: &status_list::Model,
+        purpose: StatusPurpose,
+        did: &str,
+    ) -> anyhow::Result<String> {
+        info!("Issuing {} status list {}", purpose, model.id);
+
+        let bits = match purpose {
+            StatusPurpose::Revocation => &model.revocation,
+            StatusPurpose::Suspension => &model.suspension,
         };
+        let uri = self.get_status_list_uri(&model.id, purpose);
 
-        model.credential = Some(vc_jwt.clone());
-        Ok(GiveVC {
-            format: "jwt_vc_json".to_string(),
-            credential: vc_jwt,
-        })
+        let now = Utc::now();
+        // Bitstring status lists are only defined for the 2.0 data model
+        let claims = VCClaimsV2 {
+            exp: None,
+            iat: None,
+            nbf: None,
+            iss: None,
+            sub: None,
+            context: vec![VcDataModel::V2.context().to_string()],
+            r#type: vec![
+                "VerifiableCredential".to_string(),
+                "BitstringStatusListCredential".to_string(),
+            ],
+            id: uri.clone(),
+            credential_subject: json!({
+                "id": format!("{}#list", uri),
+                "type": "BitstringStatusList",
+                "statusPurpose": purpose,
+                "encodedList": encode_status_list(bits)?,
+                "ttl": STATUS_LIST_TTL,
+            }),
+            issuer: VCIssuer {
+                id: did.to_string(),
+                name: "RainbowAuthority".to_string(),
+            },
+            valid_from: Some(now),
+            valid_until: Some(now + Duration::days(1)),
+            credential_status: None,
+            credential_schema: None,
+        };
+
+        self.sign(&claims, did, VcDataModel::V2.typ()).await
     }
 
-    fn validate_cred_req(
+    async fn validate_cred_req(
         &self,
         model: &mut issuing::Model,
         cred_req: &CredentialRequest,
@@ -274,15 +617,34 @@ impl IssuerTrait for BasicIssuerService {
         info!("Validating credential request");
 
         if model.token != token {
-            let error = Errors::forbidden_new("tx_code does not match");
+            let error = Errors::forbidden_new("Access token does not match the one issued");
+            error!("{}", error.log());
+            bail!(error)
+        }
+
+        let token_issued_at = match model.token_issued_at {
+            Some(data) => data,
+            None => {
+                let error = Errors::unauthorized_new("Token has not been issued yet");
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        if token_issued_at + Duration::seconds(ACCESS_TOKEN_TTL) < Utc::now().naive_utc() {
+            let error = Errors::unauthorized_new("Token has expired");
             error!("{}", error.log());
             bail!(error)
         }
 
-        if cred_req.format != "jwt_vc_json" {
+        let registry = self.config.get_registry();
+        let format = &registry.get(&model.vc_type)?.format;
+        if &cred_req.format != format {
             let error = Errors::format_new(
                 BadFormat::Received,
-                &format!("Cannot issue a credentia with format: {}", cred_req.format),
+                &format!(
+                    "Cannot issue a {} credential with format: {}",
+                    model.vc_type, cred_req.format
+                ),
             );
             error!("{}", error.log());
             bail!(error)
@@ -300,14 +662,54 @@ impl IssuerTrait for BasicIssuerService {
             bail!(error)
         }
 
-        let (token, kid) = validate_token::<DidPossession>(&cred_req.proof.jwt, Some(&model.aud))?;
+        let (token, kid) = validate_token::<DidPossession>(
+            &cred_req.proof.jwt,
+            Some(&model.aud),
+            self.resolver.as_ref(),
+            self.config.get_clock_skew(),
+        )
+        .await?;
         self.validate_did_possession(&token, &kid)?;
-        model.did = Some(kid);
         is_active(token.claims.iat)?;
-        has_expired(token.claims.exp)?;
+        self.validate_c_nonce(model, token.claims.nonce.as_deref())?;
+        // Renewals come bound to the holder that presented the previous credential
+        if model.did.is_some() && model.did.as_deref() != Some(kid.as_str()) {
+            let error = Errors::forbidden_new("Credential is bound to another holder");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        model.did = Some(kid);
         Ok(())
     }
 
+    fn validate_c_nonce(
+        &self,
+        model: &mut issuing::Model,
+        nonce: Option<&str>,
+    ) -> anyhow::Result<()> {
+        info!("Validating c_nonce");
+
+        let cause = match (&model.c_nonce, model.c_nonce_expires_at, nonce) {
+            (_, _, None) => "Proof does not contain a nonce",
+            (None, _, _) | (_, None, _) => "There is no c_nonce pending to be used",
+            (Some(expected), _, Some(nonce)) if expected != nonce => "Proof nonce does not match",
+            (_, Some(expires_at), _) if expires_at < Utc::now().naive_utc() => {
+                "Proof nonce has expired"
+            }
+            _ => {
+                // Each c_nonce is valid for a single proof
+                model.c_nonce = None;
+                model.c_nonce_expires_at = None;
+                return Ok(());
+            }
+        };
+
+        let fresh = self.refresh_c_nonce(model);
+        let error = Errors::invalid_proof_new(cause, &fresh.c_nonce, fresh.c_nonce_expires_in);
+        error!("{}", error.log());
+        bail!(error)
+    }
+
     fn validate_did_possession(
         &self,
         token: &TokenData<DidPossession>,
@@ -339,4 +741,54 @@ impl IssuerTrait for BasicIssuerService {
             is_me: false,
         })
     }
+
+    fn ledger_entry(&self, model: &issuing::Model) -> anyhow::Result<issued_credential::NewModel> {
+        let credential = get_from_opt(&model.credential, "credential")?;
+        let header = jsonwebtoken::decode_header(&credential)?;
+        Ok(issued_credential::NewModel {
+            id: model.credential_id.clone(),
+            issuing_id: model.id.clone(),
+            vc_type: model.vc_type.clone(),
+            subject: get_from_opt(&model.did, "did")?,
+            issuer_kid: header.kid,
+            valid_from: get_from_opt(&model.issued_at, "issued_at")?,
+            valid_until: get_from_opt(&model.expires_at, "expires_at")?,
+            status_list_id: model.status_list_id.clone(),
+            status_index: model.status_index,
+            digest: sha256_digest(&credential),
+        })
+    }
+
+    fn handle_notification(
+        &self,
+        iss_model: &issuing::Model,
+        payload: &NotificationRequest,
+        req_model: &mut request::Model,
+        minion: &mut minions::Model,
+    ) -> anyhow::Result<()> {
+        info!("Handling {:?} notification", payload.event);
+
+        if iss_model.notification_id.as_deref() != Some(payload.notification_id.as_str()) {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                &format!("Invalid notification id: {}", payload.notification_id),
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if let Some(description) = &payload.event_description {
+            info!("Wallet reported: {}", description);
+        }
+
+        let (status, is_vc_issued) = match payload.event {
+            NotificationEvent::CredentialAccepted => ("Finalized", true),
+            NotificationEvent::CredentialFailure => ("Wallet_failure", false),
+            NotificationEvent::CredentialDeleted => ("Deleted", false),
+        };
+        req_model.status = status.to_string();
+        req_model.is_vc_issued = is_vc_issued;
+        req_model.ended_at = Some(Utc::now().naive_utc());
+        minion.is_vc_issued = is_vc_issued;
+        Ok(())
+    }
 }
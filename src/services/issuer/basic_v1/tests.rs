@@ -0,0 +1,163 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::BasicIssuerService;
+use crate::config::CoreApplicationConfig;
+use crate::data::entities::issuing;
+use crate::errors::Errors;
+use crate::services::issuer::IssuerTrait;
+use crate::services::resolver::JwkDidResolver;
+use crate::services::signer::memory::config::MemorySignerConfig;
+use crate::services::signer::memory::MemorySignerService;
+use crate::services::signer::KeyMaterial;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::utils::sha256_digest;
+use axum::http::header::WWW_AUTHENTICATE;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use chrono::Utc;
+use jsonwebtoken::jwk::ThumbprintHash;
+use jsonwebtoken::Header;
+use serde_json::json;
+use std::sync::Arc;
+
+const AUD: &str = "https://issuer.example.com/api/v1/issuer";
+
+fn issuer() -> BasicIssuerService {
+    // RSA keys take long to generate in debug builds
+    let config = CoreApplicationConfig {
+        signing_alg: SigningAlgorithm::ES256,
+        ..Default::default()
+    };
+    let signer = MemorySignerService::new(MemorySignerConfig::from(config.clone())).unwrap();
+    BasicIssuerService::new(
+        config.into(),
+        Arc::new(signer),
+        Arc::new(JwkDidResolver::new()),
+    )
+}
+
+fn model() -> issuing::Model {
+    issuing::Model {
+        id: "id".to_string(),
+        name: "alice".to_string(),
+        pre_auth_code: "code".to_string(),
+        tx_code: "123456".to_string(),
+        tx_code_attempts: 0,
+        vc_type: "DataspaceParticipantCredential".to_string(),
+        uri: AUD.to_string(),
+        token: "access-token".to_string(),
+        aud: AUD.to_string(),
+        did: None,
+        credential_id: "credential".to_string(),
+        credential: None,
+        status_list_id: None,
+        status_index: None,
+        c_nonce: None,
+        c_nonce_expires_at: None,
+        created_at: Utc::now().naive_utc(),
+        token_issued_at: None,
+        issued_at: None,
+        notification_id: None,
+        expires_at: None,
+        remind_at: None,
+        reminded_at: None,
+    }
+}
+
+fn dpop_proof(key: &KeyMaterial, endpoint: &str, nonce: Option<&str>, ath: Option<&str>) -> String {
+    let header = Header {
+        typ: Some("dpop+jwt".to_string()),
+        jwk: Some(key.get_jwk()),
+        ..Default::default()
+    };
+    let claims = json!({
+        "jti": uuid::Uuid::new_v4().to_string(),
+        "htm": "POST",
+        "htu": format!("{}/{}", AUD, endpoint),
+        "iat": Utc::now().timestamp(),
+        "nonce": nonce,
+        "ath": ath.map(sha256_digest),
+    });
+    key.sign(header, &claims).unwrap()
+}
+
+fn dpop_error(result: anyhow::Result<String>) -> Errors {
+    result.unwrap_err().downcast::<Errors>().unwrap()
+}
+
+fn issuer_nonce(issuer: &BasicIssuerService, key: &KeyMaterial) -> String {
+    let proof = dpop_proof(key, "token", None, None);
+    match dpop_error(issuer.validate_dpop(&model(), Some(&proof), "token", None)) {
+        Errors::DpopError {
+            nonce: Some(nonce), ..
+        } => nonce,
+        other => panic!("expected a DPoP nonce, got {:?}", other),
+    }
+}
+
+#[test]
+fn dpop_proof_jti_cannot_be_replayed() {
+    let issuer = issuer();
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let nonce = issuer_nonce(&issuer, &key);
+
+    let proof = dpop_proof(&key, "token", Some(&nonce), None);
+    let jkt = issuer
+        .validate_dpop(&model(), Some(&proof), "token", None)
+        .unwrap();
+    assert_eq!(jkt, key.get_jwk().thumbprint(ThumbprintHash::SHA256));
+
+    match dpop_error(issuer.validate_dpop(&model(), Some(&proof), "token", None)) {
+        Errors::DpopError { cause, .. } => assert!(cause.contains("already been used")),
+        other => panic!("expected a DPoP error, got {:?}", other),
+    }
+}
+
+#[test]
+fn dpop_failure_at_the_token_endpoint_is_a_bad_request() {
+    let issuer = issuer();
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let proof = dpop_proof(&key, "credential", None, None);
+
+    let error = dpop_error(issuer.validate_dpop(&model(), Some(&proof), "token", None));
+    let response = (&error).into_response();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn dpop_failure_at_the_credential_endpoint_is_unauthorized() {
+    let issuer = issuer();
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let nonce = issuer_nonce(&issuer, &key);
+    let proof = dpop_proof(&key, "credential", Some(&nonce), Some("other-token"));
+
+    let error = dpop_error(issuer.validate_dpop(
+        &model(),
+        Some(&proof),
+        "credential",
+        Some("access-token"),
+    ));
+    let response = (&error).into_response();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers().get(WWW_AUTHENTICATE).unwrap(),
+        "DPoP error=\"invalid_dpop_proof\""
+    );
+}
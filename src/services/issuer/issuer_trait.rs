@@ -17,33 +17,76 @@
  *
  */
 
-use crate::data::entities::{interaction, issuing, minions, request};
+use crate::data::entities::{
+    interaction, issued_credential, issuing, minions, request, status_list,
+};
 use crate::types::issuing::{
     AuthServerMetadata, CredentialRequest, DidPossession, GiveVC, IssuerMetadata, IssuingToken,
-    VCCredOffer,
+    NonceResponse, NotificationRequest, VCCredOffer, WellKnownJwks,
 };
+use crate::types::status_list::StatusPurpose;
+use async_trait::async_trait;
 use jsonwebtoken::TokenData;
+use serde_json::Value;
 
+#[async_trait]
 pub trait IssuerTrait: Send + Sync + 'static {
     fn start_vci(&self, req_model: &request::Model) -> issuing::NewModel;
     fn generate_issuing_uri(&self, id: &str) -> String;
+    fn get_offer_uri(&self, model: &issuing::Model) -> anyhow::Result<String>;
     fn get_cred_offer_data(&self, model: &issuing::Model) -> anyhow::Result<VCCredOffer>;
-    fn get_issuer_data(&self) -> IssuerMetadata;
-    fn get_oauth_server_data(&self) -> AuthServerMetadata;
-    fn get_token(&self, model: &issuing::Model) -> IssuingToken;
-    fn validate_token_req(
+    fn get_issuer_data(&self) -> anyhow::Result<IssuerMetadata>;
+    fn get_oauth_server_data(&self) -> anyhow::Result<AuthServerMetadata>;
+    async fn get_jwks(&self) -> anyhow::Result<WellKnownJwks>;
+    async fn rotate_keys(&self) -> anyhow::Result<WellKnownJwks>;
+    async fn get_did(&self) -> anyhow::Result<String>;
+    async fn get_token(
+        &self,
+        model: &mut issuing::Model,
+        jkt: &str,
+    ) -> anyhow::Result<IssuingToken>;
+    async fn validate_access_token(
         &self,
         model: &issuing::Model,
-        tx_code: &str,
+        token: &str,
+        jkt: &str,
+    ) -> anyhow::Result<()>;
+    fn validate_dpop(
+        &self,
+        model: &issuing::Model,
+        proof: Option<&str>,
+        endpoint: &str,
+        access_token: Option<&str>,
+    ) -> anyhow::Result<String>;
+    fn refresh_c_nonce(&self, model: &mut issuing::Model) -> NonceResponse;
+    fn validate_token_req(
+        &self,
+        model: &mut issuing::Model,
+        tx_code: Option<&str>,
         pre_auth_code: &str,
     ) -> anyhow::Result<()>;
-    fn issue_cred(&self, model: &mut issuing::Model, did: &str) -> anyhow::Result<GiveVC>;
-    fn validate_cred_req(
+    async fn issue_cred(&self, model: &mut issuing::Model, did: &str) -> anyhow::Result<GiveVC>;
+    fn get_issued_cred(&self, model: &issuing::Model) -> anyhow::Result<GiveVC>;
+    fn get_status_list_uri(&self, id: &str, purpose: StatusPurpose) -> String;
+    fn get_schema_uri(&self, vc_type: &str) -> String;
+    fn get_credential_schema(&self, vc_type: &str) -> anyhow::Result<Value>;
+    async fn issue_status_list(
+        &self,
+        model: &status_list::Model,
+        purpose: StatusPurpose,
+        did: &str,
+    ) -> anyhow::Result<String>;
+    async fn validate_cred_req(
         &self,
         model: &mut issuing::Model,
         cred_req: &CredentialRequest,
         token: &str,
     ) -> anyhow::Result<()>;
+    fn validate_c_nonce(
+        &self,
+        model: &mut issuing::Model,
+        nonce: Option<&str>,
+    ) -> anyhow::Result<()>;
     fn validate_did_possession(
         &self,
         token: &TokenData<DidPossession>,
@@ -55,4 +98,12 @@ pub trait IssuerTrait: Send + Sync + 'static {
         int_model: &interaction::Model,
         iss_model: &issuing::Model,
     ) -> anyhow::Result<minions::NewModel>;
+    fn ledger_entry(&self, model: &issuing::Model) -> anyhow::Result<issued_credential::NewModel>;
+    fn handle_notification(
+        &self,
+        iss_model: &issuing::Model,
+        payload: &NotificationRequest,
+        req_model: &mut request::Model,
+        minion: &mut minions::Model,
+    ) -> anyhow::Result<()>;
 }
@@ -20,6 +20,10 @@
 pub mod client;
 pub mod gatekeeper;
 pub mod issuer;
+pub mod notifier;
 pub mod repo;
+pub mod resolver;
+pub mod signer;
+pub mod status_checker;
 pub mod verifier;
 pub mod wallet;
@@ -0,0 +1,43 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::SmtpNotifierConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::types::notifier::SmtpConfig;
+
+pub struct SmtpNotifierConfig {
+    smtp_config: SmtpConfig,
+}
+
+impl From<CoreApplicationConfig> for SmtpNotifierConfig {
+    fn from(config: CoreApplicationConfig) -> SmtpNotifierConfig {
+        SmtpNotifierConfig {
+            smtp_config: config.smtp_config,
+        }
+    }
+}
+
+impl SmtpNotifierConfigTrait for SmtpNotifierConfig {
+    fn get_raw_smtp_config(&self) -> SmtpConfig {
+        self.smtp_config.clone()
+    }
+    fn get_smtp_url(&self) -> String {
+        format!("smtp://{}:{}", self.smtp_config.host, self.smtp_config.port)
+    }
+}
@@ -0,0 +1,91 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{SmtpNotifierConfig, SmtpNotifierConfigTrait};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::notifier::NotifierTrait;
+use crate::types::enums::errors::BadFormat;
+use crate::types::notifier::TxCodeNotice;
+use crate::utils::get_from_opt;
+use anyhow::bail;
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use tracing::{error, info};
+
+pub struct SmtpNotifierService {
+    config: SmtpNotifierConfig,
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpNotifierService {
+    pub fn new(config: SmtpNotifierConfig) -> anyhow::Result<SmtpNotifierService> {
+        let smtp = config.get_raw_smtp_config();
+        // Without TLS it talks plain SMTP, which is what local stand-ins like mailpit expect
+        let builder = match smtp.tls {
+            true => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&smtp.host)?,
+            false => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&smtp.host),
+        };
+        let builder = match (smtp.user, smtp.password) {
+            (Some(user), Some(password)) => builder.credentials(Credentials::new(user, password)),
+            _ => builder,
+        };
+        let mailer = builder.port(smtp.port).build();
+        Ok(SmtpNotifierService { config, mailer })
+    }
+}
+
+#[async_trait]
+impl NotifierTrait for SmtpNotifierService {
+    async fn send_tx_code(&self, notice: &TxCodeNotice) -> anyhow::Result<()> {
+        info!("Sending tx_code by email");
+
+        let contact = get_from_opt(&notice.contact, "contact")?;
+        let message = Message::builder()
+            .from(self.config.get_raw_smtp_config().from.parse()?)
+            .to(match contact.parse() {
+                Ok(data) => data,
+                Err(e) => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        &format!("Invalid email contact {}: {}", contact, e),
+                    );
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            })
+            .subject(format!("PIN for your {} credential", notice.vc_type))
+            .header(ContentType::TEXT_PLAIN)
+            .body(format!(
+                "Hello {},\n\nUse the following PIN when your wallet asks for it to accept the {} credential offer:\n\n{}\n\nIf you did not request this credential you can ignore this email.\n",
+                notice.participant_slug, notice.vc_type, notice.tx_code
+            ))?;
+
+        match self.mailer.send(message).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let error =
+                    Errors::petition_new(&self.config.get_smtp_url(), "SMTP", None, &e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::SmtpNotifierConfig;
+use super::SmtpNotifierService;
+use crate::config::CoreApplicationConfig;
+use crate::services::notifier::NotifierTrait;
+use crate::types::notifier::{SmtpConfig, TxCodeNotice};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+// Plain SMTP stand-in: it answers one session and hands back the message, or
+// rejects the recipient when asked to
+async fn smtp_stand_in(reject_rcpt: bool) -> (u16, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let (read, mut write) = stream.into_split();
+        let mut lines = BufReader::new(read).lines();
+        let mut data = String::new();
+        let mut in_data = false;
+        write.write_all(b"220 localhost ESMTP\r\n").await.unwrap();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if in_data {
+                match line.as_str() {
+                    "." => {
+                        in_data = false;
+                        write.write_all(b"250 Queued\r\n").await.unwrap();
+                    }
+                    _ => data.push_str(&format!("{}\n", line)),
+                }
+                continue;
+            }
+            let command = line.split([' ', ':']).next().unwrap_or("").to_uppercase();
+            let reply: &[u8] = match command.as_str() {
+                "EHLO" | "HELO" => b"250 localhost\r\n",
+                "RCPT" if reject_rcpt => b"550 No such user\r\n",
+                "DATA" => {
+                    in_data = true;
+                    b"354 End data with <CR><LF>.<CR><LF>\r\n"
+                }
+                "QUIT" => {
+                    write.write_all(b"221 Bye\r\n").await.unwrap();
+                    break;
+                }
+                _ => b"250 OK\r\n",
+            };
+            write.write_all(reply).await.unwrap();
+        }
+        data
+    });
+    (port, handle)
+}
+
+fn notifier(port: u16) -> SmtpNotifierService {
+    let config = CoreApplicationConfig {
+        smtp_config: SmtpConfig {
+            host: "127.0.0.1".to_string(),
+            port,
+            user: None,
+            password: None,
+            from: "authority@example.com".to_string(),
+            tls: false,
+        },
+        ..Default::default()
+    };
+    SmtpNotifierService::new(SmtpNotifierConfig::from(config)).unwrap()
+}
+
+fn notice(contact: Option<&str>) -> TxCodeNotice {
+    TxCodeNotice {
+        request_id: "request".to_string(),
+        participant_slug: "alice".to_string(),
+        contact: contact.map(|contact| contact.to_string()),
+        vc_type: "DataspaceParticipantCredential".to_string(),
+        tx_code: "493028".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn tx_code_is_mailed_to_the_contact() {
+    let (port, server) = smtp_stand_in(false).await;
+    notifier(port)
+        .send_tx_code(&notice(Some("alice@example.com")))
+        .await
+        .unwrap();
+
+    let message = server.await.unwrap();
+    assert!(message.contains("To: alice@example.com"));
+    assert!(message.contains("From: authority@example.com"));
+    assert!(message.contains("Subject: PIN for your DataspaceParticipantCredential credential"));
+    assert!(message.contains("493028"));
+}
+
+#[tokio::test]
+async fn rejected_recipient_is_an_error() {
+    let (port, server) = smtp_stand_in(true).await;
+    let result = notifier(port)
+        .send_tx_code(&notice(Some("nobody@example.com")))
+        .await;
+
+    assert!(result.is_err());
+    server.abort();
+}
+
+#[tokio::test]
+async fn missing_contact_is_an_error() {
+    let (port, server) = smtp_stand_in(false).await;
+    let result = notifier(port).send_tx_code(&notice(None)).await;
+
+    assert!(result.is_err());
+    server.abort();
+}
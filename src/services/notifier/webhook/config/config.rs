@@ -0,0 +1,40 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::WebhookNotifierConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::utils::get_from_opt;
+
+pub struct WebhookNotifierConfig {
+    webhook_url: Option<String>,
+}
+
+impl From<CoreApplicationConfig> for WebhookNotifierConfig {
+    fn from(config: CoreApplicationConfig) -> WebhookNotifierConfig {
+        WebhookNotifierConfig {
+            webhook_url: config.webhook_url,
+        }
+    }
+}
+
+impl WebhookNotifierConfigTrait for WebhookNotifierConfig {
+    fn get_webhook_url(&self) -> anyhow::Result<String> {
+        get_from_opt(&self.webhook_url, "NOTIFIER_WEBHOOK_URL")
+    }
+}
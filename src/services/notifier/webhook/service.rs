@@ -0,0 +1,77 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{WebhookNotifierConfig, WebhookNotifierConfigTrait};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::client::ClientServiceTrait;
+use crate::services::notifier::NotifierTrait;
+use crate::types::enums::request::Body;
+use crate::types::notifier::TxCodeNotice;
+use anyhow::bail;
+use async_trait::async_trait;
+use axum::http::header::{ACCEPT, CONTENT_TYPE};
+use axum::http::HeaderMap;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct WebhookNotifierService {
+    config: WebhookNotifierConfig,
+    client: Arc<dyn ClientServiceTrait>,
+}
+
+impl WebhookNotifierService {
+    pub fn new(
+        config: WebhookNotifierConfig,
+        client: Arc<dyn ClientServiceTrait>,
+    ) -> WebhookNotifierService {
+        WebhookNotifierService { config, client }
+    }
+}
+
+#[async_trait]
+impl NotifierTrait for WebhookNotifierService {
+    async fn send_tx_code(&self, notice: &TxCodeNotice) -> anyhow::Result<()> {
+        info!("Sending tx_code to webhook");
+
+        let url = self.config.get_webhook_url()?;
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse()?);
+        headers.insert(ACCEPT, "application/json".parse()?);
+        let body = serde_json::to_value(notice)?;
+
+        let res = self
+            .client
+            .post(&url, Some(headers), Body::Json(body))
+            .await?;
+
+        match res.status().is_success() {
+            true => Ok(()),
+            false => {
+                let error = Errors::petition_new(
+                    &url,
+                    "POST",
+                    Some(res.status().as_u16()),
+                    "Webhook did not accept the tx_code",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
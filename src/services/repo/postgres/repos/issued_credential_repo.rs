@@ -0,0 +1,125 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::super::subtraits::{BasicRepoTrait, IssuedCredentialRepoTrait};
+use crate::data::entities::issued_credential::{Column, Entity, Model, NewModel};
+use crate::data::IntoActiveSet;
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::ledger::{LedgerQuery, LedgerStatus};
+use crate::types::status_list::StatusPurpose;
+use anyhow::bail;
+use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+
+#[derive(Clone)]
+pub struct IssuedCredentialRepo {
+    db_connection: DatabaseConnection,
+}
+
+impl IssuedCredentialRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+impl BasicRepoTrait<Entity, NewModel> for IssuedCredentialRepo {
+    fn db(&self) -> &DatabaseConnection {
+        &self.db_connection
+    }
+}
+
+#[async_trait]
+impl IssuedCredentialRepoTrait for IssuedCredentialRepo {
+    async fn create_if_missing(&self, entry: NewModel) -> anyhow::Result<()> {
+        let result = Entity::insert(entry.to_active())
+            .on_conflict(OnConflict::column(Column::Id).do_nothing().to_owned())
+            .exec_without_returning(self.db())
+            .await;
+        if let Err(e) = result {
+            let error = Errors::database_new(&e.to_string());
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(())
+    }
+
+    async fn search(&self, query: &LedgerQuery) -> anyhow::Result<Vec<Model>> {
+        let mut select = Entity::find();
+        if let Some(subject) = &query.subject {
+            select = select.filter(Column::Subject.eq(subject));
+        }
+        if let Some(vc_type) = &query.vc_type {
+            select = select.filter(Column::VcType.eq(vc_type));
+        }
+        if let Some(issued_from) = query.issued_from {
+            select = select.filter(Column::ValidFrom.gte(issued_from));
+        }
+        if let Some(issued_to) = query.issued_to {
+            select = select.filter(Column::ValidFrom.lte(issued_to));
+        }
+        let now = chrono::Utc::now().naive_utc();
+        select = match query.status {
+            Some(LedgerStatus::Active) => select
+                .filter(Column::Revoked.eq(false))
+                .filter(Column::Suspended.eq(false))
+                .filter(Column::ValidUntil.gt(now)),
+            Some(LedgerStatus::Expired) => select.filter(Column::ValidUntil.lte(now)),
+            Some(LedgerStatus::Revoked) => select.filter(Column::Revoked.eq(true)),
+            Some(LedgerStatus::Suspended) => select.filter(Column::Suspended.eq(true)),
+            None => select,
+        };
+
+        match select.order_by_desc(Column::ValidFrom).all(self.db()).await {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    async fn set_status(
+        &self,
+        id: &str,
+        purpose: StatusPurpose,
+        value: bool,
+    ) -> anyhow::Result<()> {
+        let column = match purpose {
+            StatusPurpose::Revocation => Column::Revoked,
+            StatusPurpose::Suspension => Column::Suspended,
+        };
+        // Credentials issued before the ledger existed have no entry to update
+        match Entity::update_many()
+            .col_expr(column, Expr::value(value))
+            .filter(Column::Id.eq(id))
+            .exec(self.db())
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
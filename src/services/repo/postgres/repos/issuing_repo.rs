@@ -22,6 +22,7 @@ use crate::data::entities::issuing::{Column, Entity, Model, NewModel};
 use crate::errors::{ErrorLogTrait, Errors};
 use anyhow::bail;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
 use tracing::error;
 
@@ -44,9 +45,9 @@ impl BasicRepoTrait<Entity, NewModel> for IssuingRepo {
 
 #[async_trait]
 impl IssuingRepoTrait for IssuingRepo {
-    async fn get_by_tx_code(&self, code: &str) -> anyhow::Result<Model> {
+    async fn get_by_pre_auth_code(&self, code: &str) -> anyhow::Result<Model> {
         let model = match Entity::find()
-            .filter(Column::TxCode.eq(code))
+            .filter(Column::PreAuthCode.eq(code))
             .one(self.db())
             .await
         {
@@ -91,4 +92,60 @@ impl IssuingRepoTrait for IssuingRepo {
         };
         Ok(model)
     }
+
+    async fn get_by_credential_id(&self, credential_id: &str) -> anyhow::Result<Model> {
+        let model = match Entity::find()
+            .filter(Column::CredentialId.eq(credential_id))
+            .one(self.db())
+            .await
+        {
+            Ok(Some(data)) => data,
+            Ok(None) => {
+                let error = Errors::missing_resource_new(
+                    credential_id,
+                    &format!("Missing resource with credential id: {}", credential_id),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        Ok(model)
+    }
+
+    async fn get_by_did(&self, did: &str) -> anyhow::Result<Vec<Model>> {
+        match Entity::find()
+            .filter(Column::Did.eq(did))
+            .all(self.db())
+            .await
+        {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    async fn get_due_reminders(&self, now: NaiveDateTime) -> anyhow::Result<Vec<Model>> {
+        match Entity::find()
+            .filter(Column::RemindAt.lte(now))
+            .filter(Column::RemindedAt.is_null())
+            .filter(Column::ExpiresAt.gt(now))
+            .all(self.db())
+            .await
+        {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
 }
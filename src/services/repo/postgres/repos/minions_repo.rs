@@ -92,4 +92,16 @@ impl MinionsRepoTrait for MinionsRepo {
         };
         Ok(mate)
     }
+
+    async fn is_revoked(&self, participant_id: &str) -> anyhow::Result<bool> {
+        match Entity::find_by_id(participant_id).one(self.db()).await {
+            Ok(Some(data)) => Ok(data.is_revoked),
+            Ok(None) => Ok(false),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
 }
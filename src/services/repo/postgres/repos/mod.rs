@@ -18,13 +18,21 @@
  */
 
 mod interaction_repo;
+mod issued_credential_repo;
 mod issuing_repo;
 mod minions_repo;
 mod request_repo;
+mod revocation_repo;
+mod status_list_repo;
+mod trusted_issuer_repo;
 mod verification_repo;
 
 pub use interaction_repo::InteractionRepo;
+pub use issued_credential_repo::IssuedCredentialRepo;
 pub use issuing_repo::IssuingRepo;
 pub use minions_repo::MinionsRepo;
 pub use request_repo::AuthRequestRepo;
+pub use revocation_repo::RevocationRepo;
+pub use status_list_repo::StatusListRepo;
+pub use trusted_issuer_repo::TrustedIssuerRepo;
 pub use verification_repo::AuthVerificationRepo;
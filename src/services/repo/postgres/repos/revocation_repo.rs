@@ -0,0 +1,62 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::super::subtraits::{BasicRepoTrait, RevocationRepoTrait};
+use crate::data::entities::revocation::{Column, Entity, Model, NewModel};
+use crate::errors::{ErrorLogTrait, Errors};
+use anyhow::bail;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+
+#[derive(Clone)]
+pub struct RevocationRepo {
+    db_connection: DatabaseConnection,
+}
+
+impl RevocationRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+impl BasicRepoTrait<Entity, NewModel> for RevocationRepo {
+    fn db(&self) -> &DatabaseConnection {
+        &self.db_connection
+    }
+}
+
+#[async_trait]
+impl RevocationRepoTrait for RevocationRepo {
+    async fn get_by_participant(&self, participant_id: &str) -> anyhow::Result<Vec<Model>> {
+        match Entity::find()
+            .filter(Column::ParticipantId.eq(participant_id))
+            .order_by_desc(Column::CreatedAt)
+            .all(self.db())
+            .await
+        {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::super::subtraits::{BasicRepoTrait, StatusListRepoTrait};
+use crate::data::entities::status_list::{Column, Entity, Model, NewModel};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::status_list::StatusPurpose;
+use crate::utils::{random_free_status_index, set_status_bit};
+use anyhow::bail;
+use async_trait::async_trait;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder};
+use tracing::error;
+
+#[derive(Clone)]
+pub struct StatusListRepo {
+    db_connection: DatabaseConnection,
+}
+
+impl StatusListRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+
+    // next_index counts the indexes handed out, it also tells whether the list changed meanwhile
+    async fn save_allocation(&self, list: &Model, count: i64) -> anyhow::Result<bool> {
+        match Entity::update_many()
+            .col_expr(Column::NextIndex, Expr::value(count))
+            .col_expr(Column::Allocated, Expr::value(list.allocated.clone()))
+            .filter(Column::Id.eq(list.id.clone()))
+            .filter(Column::NextIndex.eq(list.next_index))
+            .exec(self.db())
+            .await
+        {
+            Ok(data) => Ok(data.rows_affected == 1),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
+
+impl BasicRepoTrait<Entity, NewModel> for StatusListRepo {
+    fn db(&self) -> &DatabaseConnection {
+        &self.db_connection
+    }
+}
+
+#[async_trait]
+impl StatusListRepoTrait for StatusListRepo {
+    async fn allocate_index(&self, size: i64) -> anyhow::Result<(String, i64)> {
+        loop {
+            let mut list = match Entity::find()
+                .filter(Expr::col(Column::NextIndex).lt(Expr::col(Column::Size)))
+                .order_by_asc(Column::CreatedAt)
+                .one(self.db())
+                .await
+            {
+                Ok(Some(data)) => data,
+                Ok(None) => self.create(NewModel { size }).await?,
+                Err(e) => {
+                    let error = Errors::database_new(&e.to_string());
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            };
+
+            // Random indexes keep the list position from revealing when a credential was issued
+            let index = match random_free_status_index(&list.allocated, list.size)? {
+                Some(data) => data,
+                None => {
+                    let error = Errors::database_new(&format!("Status list {} is full", list.id));
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            };
+            set_status_bit(&mut list.allocated, index, true)?;
+
+            // Only one petition can take the index, the rest will try again with another one
+            if self.save_allocation(&list, list.next_index + 1).await? {
+                return Ok((list.id, index));
+            }
+        }
+    }
+
+    async fn release_index(&self, id: &str, index: i64) -> anyhow::Result<()> {
+        loop {
+            let mut list = self.get_by_id(id).await?;
+            set_status_bit(&mut list.allocated, index, false)?;
+            if self.save_allocation(&list, list.next_index - 1).await? {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn set_status(
+        &self,
+        id: &str,
+        purpose: StatusPurpose,
+        index: i64,
+        value: bool,
+    ) -> anyhow::Result<()> {
+        loop {
+            let mut model = self.get_by_id(id).await?;
+            let (column, bits) = match purpose {
+                StatusPurpose::Revocation => (Column::Revocation, &mut model.revocation),
+                StatusPurpose::Suspension => (Column::Suspension, &mut model.suspension),
+            };
+            set_status_bit(bits, index, value)?;
+
+            // The list is only written if nobody else has modified it since it was read
+            let result = match Entity::update_many()
+                .col_expr(column, Expr::value(bits.clone()))
+                .col_expr(
+                    Column::UpdatedAt,
+                    Expr::value(chrono::Utc::now().naive_utc()),
+                )
+                .filter(Column::Id.eq(id))
+                .filter(Column::UpdatedAt.eq(model.updated_at))
+                .exec(self.db())
+                .await
+            {
+                Ok(data) => data,
+                Err(e) => {
+                    let error = Errors::database_new(&e.to_string());
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            };
+
+            if result.rows_affected == 1 {
+                return Ok(());
+            }
+        }
+    }
+}
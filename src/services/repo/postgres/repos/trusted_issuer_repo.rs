@@ -0,0 +1,61 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::super::super::subtraits::{BasicRepoTrait, TrustedIssuerRepoTrait};
+use crate::data::entities::trusted_issuer::{Column, Entity, Model, NewModel};
+use crate::errors::{ErrorLogTrait, Errors};
+use anyhow::bail;
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use tracing::error;
+
+#[derive(Clone)]
+pub struct TrustedIssuerRepo {
+    db_connection: DatabaseConnection,
+}
+
+impl TrustedIssuerRepo {
+    pub fn new(db_connection: DatabaseConnection) -> Self {
+        Self { db_connection }
+    }
+}
+
+impl BasicRepoTrait<Entity, NewModel> for TrustedIssuerRepo {
+    fn db(&self) -> &DatabaseConnection {
+        &self.db_connection
+    }
+}
+
+#[async_trait]
+impl TrustedIssuerRepoTrait for TrustedIssuerRepo {
+    async fn get_by_issuer(&self, issuer: &str) -> anyhow::Result<Vec<Model>> {
+        match Entity::find()
+            .filter(Column::Issuer.eq(issuer))
+            .all(self.db())
+            .await
+        {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error = Errors::database_new(&e.to_string());
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
@@ -18,10 +18,15 @@
  */
 
 use super::super::subtraits::{
-    InteractionRepoTrait, IssuingRepoTrait, MinionsRepoTrait, RequestRepoTrait, VerificationRepoTrait,
+    InteractionRepoTrait, IssuedCredentialRepoTrait, IssuingRepoTrait, MinionsRepoTrait,
+    RequestRepoTrait, RevocationRepoTrait, StatusListRepoTrait, TrustedIssuerRepoTrait,
+    VerificationRepoTrait,
 };
 use super::super::RepoTrait;
-use super::repos::{AuthRequestRepo, AuthVerificationRepo, InteractionRepo, IssuingRepo, MinionsRepo};
+use super::repos::{
+    AuthRequestRepo, AuthVerificationRepo, InteractionRepo, IssuedCredentialRepo, IssuingRepo,
+    MinionsRepo, RevocationRepo, StatusListRepo, TrustedIssuerRepo,
+};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
@@ -32,6 +37,10 @@ pub struct RepoForSql {
     verification_repo: Arc<dyn VerificationRepoTrait>,
     issuing_repo: Arc<dyn IssuingRepoTrait>,
     minions_repo: Arc<dyn MinionsRepoTrait>,
+    status_list_repo: Arc<dyn StatusListRepoTrait>,
+    revocation_repo: Arc<dyn RevocationRepoTrait>,
+    issued_credential_repo: Arc<dyn IssuedCredentialRepoTrait>,
+    trusted_issuer_repo: Arc<dyn TrustedIssuerRepoTrait>,
 }
 
 impl RepoForSql {
@@ -42,6 +51,10 @@ impl RepoForSql {
             verification_repo: Arc::new(AuthVerificationRepo::new(db_connection.clone())),
             issuing_repo: Arc::new(IssuingRepo::new(db_connection.clone())),
             minions_repo: Arc::new(MinionsRepo::new(db_connection.clone())),
+            status_list_repo: Arc::new(StatusListRepo::new(db_connection.clone())),
+            revocation_repo: Arc::new(RevocationRepo::new(db_connection.clone())),
+            issued_credential_repo: Arc::new(IssuedCredentialRepo::new(db_connection.clone())),
+            trusted_issuer_repo: Arc::new(TrustedIssuerRepo::new(db_connection.clone())),
         }
     }
 }
@@ -65,4 +78,20 @@ impl RepoTrait for RepoForSql {
     fn minions(&self) -> Arc<dyn MinionsRepoTrait> {
         self.minions_repo.clone()
     }
+
+    fn status_list(&self) -> Arc<dyn StatusListRepoTrait> {
+        self.status_list_repo.clone()
+    }
+
+    fn revocation(&self) -> Arc<dyn RevocationRepoTrait> {
+        self.revocation_repo.clone()
+    }
+
+    fn issued_credential(&self) -> Arc<dyn IssuedCredentialRepoTrait> {
+        self.issued_credential_repo.clone()
+    }
+
+    fn trusted_issuer(&self) -> Arc<dyn TrustedIssuerRepoTrait> {
+        self.trusted_issuer_repo.clone()
+    }
 }
@@ -18,7 +18,8 @@
  */
 
 use super::subtraits::{
-    InteractionRepoTrait, IssuingRepoTrait, MinionsRepoTrait, RequestRepoTrait,
+    InteractionRepoTrait, IssuedCredentialRepoTrait, IssuingRepoTrait, MinionsRepoTrait,
+    RequestRepoTrait, RevocationRepoTrait, StatusListRepoTrait, TrustedIssuerRepoTrait,
     VerificationRepoTrait,
 };
 use std::sync::Arc;
@@ -29,4 +30,8 @@ pub trait RepoTrait: Send + Sync + 'static {
     fn verification(&self) -> Arc<dyn VerificationRepoTrait>;
     fn minions(&self) -> Arc<dyn MinionsRepoTrait>;
     fn issuing(&self) -> Arc<dyn IssuingRepoTrait>;
+    fn status_list(&self) -> Arc<dyn StatusListRepoTrait>;
+    fn revocation(&self) -> Arc<dyn RevocationRepoTrait>;
+    fn issued_credential(&self) -> Arc<dyn IssuedCredentialRepoTrait>;
+    fn trusted_issuer(&self) -> Arc<dyn TrustedIssuerRepoTrait>;
 }
@@ -20,9 +20,13 @@
 use super::BasicRepoTrait;
 use crate::data::entities::issuing::{Entity, Model, NewModel};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
 #[async_trait]
 pub trait IssuingRepoTrait: BasicRepoTrait<Entity, NewModel> + Send + Sync {
-    async fn get_by_tx_code(&self, code: &str) -> anyhow::Result<Model>;
+    async fn get_by_pre_auth_code(&self, code: &str) -> anyhow::Result<Model>;
     async fn get_by_token(&self, token: &str) -> anyhow::Result<Model>;
+    async fn get_by_credential_id(&self, credential_id: &str) -> anyhow::Result<Model>;
+    async fn get_by_did(&self, did: &str) -> anyhow::Result<Vec<Model>>;
+    async fn get_due_reminders(&self, now: NaiveDateTime) -> anyhow::Result<Vec<Model>>;
 }
@@ -25,4 +25,5 @@ use async_trait::async_trait;
 pub trait MinionsRepoTrait: BasicRepoTrait<Entity, NewModel> + Send + Sync {
     async fn get_me(&self) -> anyhow::Result<Model>;
     async fn force_create(&self, mate: NewModel) -> anyhow::Result<Model>;
+    async fn is_revoked(&self, participant_id: &str) -> anyhow::Result<bool>;
 }
@@ -22,6 +22,10 @@ mod verification_trait;
 mod basic_repo_trait;
 mod minions_trait;
 mod issuing_trait;
+mod status_list_trait;
+mod revocation_trait;
+mod issued_credential_trait;
+mod trusted_issuer_trait;
 
 pub use interaction_trait::InteractionRepoTrait;
 pub use request_trait::RequestRepoTrait;
@@ -29,3 +33,7 @@ pub use verification_trait::VerificationRepoTrait;
 pub use basic_repo_trait::BasicRepoTrait;
 pub use minions_trait::MinionsRepoTrait;
 pub use issuing_trait::IssuingRepoTrait;
+pub use status_list_trait::StatusListRepoTrait;
+pub use revocation_trait::RevocationRepoTrait;
+pub use issued_credential_trait::IssuedCredentialRepoTrait;
+pub use trusted_issuer_trait::TrustedIssuerRepoTrait;
@@ -0,0 +1,27 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::BasicRepoTrait;
+use crate::data::entities::trusted_issuer::{Entity, Model, NewModel};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TrustedIssuerRepoTrait: BasicRepoTrait<Entity, NewModel> + Send + Sync {
+    async fn get_by_issuer(&self, issuer: &str) -> anyhow::Result<Vec<Model>>;
+}
@@ -0,0 +1,39 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::CachedResolverConfigTrait;
+use crate::config::CoreApplicationConfig;
+
+pub struct CachedResolverConfig {
+    cache_ttl: u64,
+}
+
+impl From<CoreApplicationConfig> for CachedResolverConfig {
+    fn from(config: CoreApplicationConfig) -> CachedResolverConfig {
+        CachedResolverConfig {
+            cache_ttl: config.did_cache_ttl,
+        }
+    }
+}
+
+impl CachedResolverConfigTrait for CachedResolverConfig {
+    fn get_cache_ttl(&self) -> i64 {
+        self.cache_ttl as i64
+    }
+}
@@ -0,0 +1,89 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{CachedResolverConfig, CachedResolverConfigTrait};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::client::ClientServiceTrait;
+use crate::services::resolver::{DidResolverTrait, JwkDidResolver, KeyDidResolver, WebDidResolver};
+use crate::types::did::DidDocument;
+use anyhow::bail;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+
+pub struct CachedResolverService {
+    config: CachedResolverConfig,
+    resolvers: HashMap<&'static str, Arc<dyn DidResolverTrait>>,
+    cache: Mutex<HashMap<String, (i64, DidDocument)>>,
+}
+
+impl CachedResolverService {
+    pub fn new(
+        config: CachedResolverConfig,
+        client: Arc<dyn ClientServiceTrait>,
+    ) -> CachedResolverService {
+        let mut resolvers: HashMap<&'static str, Arc<dyn DidResolverTrait>> = HashMap::new();
+        resolvers.insert("jwk", Arc::new(JwkDidResolver::new()));
+        resolvers.insert("key", Arc::new(KeyDidResolver::new()));
+        resolvers.insert("web", Arc::new(WebDidResolver::new(client)));
+        CachedResolverService {
+            config,
+            resolvers,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, did: &str) -> Option<DidDocument> {
+        let now = Utc::now().timestamp();
+        let mut cache = self.cache.lock().unwrap();
+        cache.retain(|_, (expires_at, _)| *expires_at > now);
+        cache.get(did).map(|(_, document)| document.clone())
+    }
+}
+
+#[async_trait]
+impl DidResolverTrait for CachedResolverService {
+    async fn resolve(&self, did: &str) -> anyhow::Result<DidDocument> {
+        if let Some(document) = self.cached(did) {
+            return Ok(document);
+        }
+
+        let method = did.strip_prefix("did:").and_then(|id| id.split(':').next());
+        let resolver = match method.and_then(|method| self.resolvers.get(method)) {
+            Some(resolver) => resolver,
+            None => {
+                let error =
+                    Errors::not_impl_new("DID resolution", &format!("Cannot resolve DID {}", did));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        let document = resolver.resolve(did).await?;
+        info!("DID {} resolved", did);
+        let expires_at = Utc::now().timestamp() + self.config.get_cache_ttl();
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(did.to_string(), (expires_at, document.clone()));
+        Ok(document)
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::DidResolverTrait;
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::did::DidDocument;
+use crate::types::enums::errors::BadFormat;
+use anyhow::bail;
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::Value;
+use tracing::error;
+
+#[derive(Default)]
+pub struct JwkDidResolver;
+
+impl JwkDidResolver {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DidResolverTrait for JwkDidResolver {
+    async fn resolve(&self, did: &str) -> anyhow::Result<DidDocument> {
+        let jwk = did
+            .strip_prefix("did:jwk:")
+            .and_then(|encoded| URL_SAFE_NO_PAD.decode(encoded).ok())
+            .and_then(|decoded| serde_json::from_slice::<Value>(&decoded).ok());
+        match jwk {
+            Some(jwk) => Ok(DidDocument::with_jwk(did, "0", jwk)),
+            None => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("{} is not a valid did:jwk", did),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
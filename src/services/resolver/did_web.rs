@@ -0,0 +1,102 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::DidResolverTrait;
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::client::ClientServiceTrait;
+use crate::types::did::DidDocument;
+use crate::types::enums::errors::BadFormat;
+use anyhow::bail;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct WebDidResolver {
+    client: Arc<dyn ClientServiceTrait>,
+}
+
+impl WebDidResolver {
+    pub fn new(client: Arc<dyn ClientServiceTrait>) -> Self {
+        Self { client }
+    }
+
+    // did:web:example.com:user:alice -> https://example.com/user/alice/did.json
+    fn document_url(did: &str) -> anyhow::Result<String> {
+        let segments = match did.strip_prefix("did:web:") {
+            Some(id) if !id.is_empty() => id
+                .split(':')
+                .map(|segment| urlencoding::decode(segment).map(|s| s.into_owned()))
+                .collect::<Result<Vec<String>, _>>()?,
+            _ => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("{} is not a valid did:web", did),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        match segments.as_slice() {
+            [host] => Ok(format!("https://{}/.well-known/did.json", host)),
+            [host, path @ ..] => Ok(format!("https://{}/{}/did.json", host, path.join("/"))),
+            [] => unreachable!(),
+        }
+    }
+}
+
+#[async_trait]
+impl DidResolverTrait for WebDidResolver {
+    async fn resolve(&self, did: &str) -> anyhow::Result<DidDocument> {
+        let url = Self::document_url(did)?;
+        info!("Resolving {} from {}", did, url);
+
+        let res = self.client.get(&url, None).await?;
+        if !res.status().is_success() {
+            let error = Errors::petition_new(
+                &url,
+                "GET",
+                Some(res.status().as_u16()),
+                "DID document could not be retrieved",
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+
+        let document: DidDocument = match res.json().await {
+            Ok(document) => document,
+            Err(e) => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("DID document of {} is malformed -> {}", did, e),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        if document.id != did {
+            let error = Errors::security_new(&format!(
+                "DID document id {} does not match {}",
+                document.id, did
+            ));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(document)
+    }
+}
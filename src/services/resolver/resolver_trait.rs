@@ -0,0 +1,79 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::did::DidDocument;
+use crate::types::enums::errors::BadFormat;
+use crate::utils::{multikey_to_jwk, split_did};
+use anyhow::bail;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::Jwk;
+use tracing::error;
+
+#[async_trait]
+pub trait DidResolverTrait: Send + Sync + 'static {
+    async fn resolve(&self, did: &str) -> anyhow::Result<DidDocument>;
+    // Picks the verification method named by the kid fragment, or the first one without it
+    async fn resolve_key(&self, kid: &str) -> anyhow::Result<Jwk> {
+        let (did, fragment) = split_did(kid);
+        let document = self.resolve(did).await?;
+        let method = match fragment {
+            Some(fragment) => document
+                .verification_method
+                .iter()
+                .find(|method| method.id == kid || method.id == format!("#{}", fragment)),
+            None => document.verification_method.first(),
+        };
+        let method = match method {
+            Some(method) => method,
+            None => {
+                let error = Errors::missing_resource_new(
+                    kid,
+                    &format!("DID document has no verification method {}", kid),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        let jwk = match (&method.public_key_jwk, &method.public_key_multibase) {
+            (Some(jwk), _) => jwk.clone(),
+            (None, Some(multikey)) => multikey_to_jwk(multikey)?,
+            (None, None) => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("Verification method {} carries no public key", method.id),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        match serde_json::from_value::<Jwk>(jwk) {
+            Ok(jwk) => Ok(jwk),
+            Err(e) => {
+                let error = Errors::security_new(&format!(
+                    "Key of {} cannot be used to verify signatures -> {}",
+                    method.id, e
+                ));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::FileSignerConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::utils::{list_files, read};
+
+pub struct FileSignerConfig {
+    keys_path: String,
+    algorithm: SigningAlgorithm,
+}
+
+impl From<CoreApplicationConfig> for FileSignerConfig {
+    fn from(config: CoreApplicationConfig) -> FileSignerConfig {
+        FileSignerConfig {
+            keys_path: config.keys_path,
+            algorithm: config.signing_alg,
+        }
+    }
+}
+
+impl FileSignerConfigTrait for FileSignerConfig {
+    fn get_priv_key(&self) -> anyhow::Result<String> {
+        read(&self.get_priv_key_path())
+    }
+    fn get_next_key(&self) -> anyhow::Result<Option<String>> {
+        match list_files(&self.keys_path)?.contains(&"next_key.pem".to_string()) {
+            true => Ok(Some(read(&self.get_next_key_path())?)),
+            false => Ok(None),
+        }
+    }
+    fn get_retired_keys(&self) -> anyhow::Result<Vec<(SigningAlgorithm, String)>> {
+        let path = format!("{}/retired", self.keys_path);
+        let mut keys = Vec::new();
+        // Retired keys are stored as "{alg}_{kid}.pem" so an algorithm change does not orphan them
+        for file in list_files(&path)? {
+            let name = file.strip_suffix(".pem").unwrap_or(&file);
+            let alg = match name.split_once('_') {
+                Some((alg, _)) => alg.parse()?,
+                None => continue,
+            };
+            keys.push((alg, read(&format!("{}/{}", path, file))?));
+        }
+        Ok(keys)
+    }
+    fn get_priv_key_path(&self) -> String {
+        format!("{}/private_key.pem", self.keys_path)
+    }
+    fn get_next_key_path(&self) -> String {
+        format!("{}/next_key.pem", self.keys_path)
+    }
+    fn get_retired_key_path(&self, algorithm: SigningAlgorithm, kid: &str) -> String {
+        format!("{}/retired/{}_{}.pem", self.keys_path, algorithm, kid)
+    }
+    fn get_algorithm(&self) -> SigningAlgorithm {
+        self.algorithm
+    }
+}
@@ -0,0 +1,30 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::types::enums::signing_alg::SigningAlgorithm;
+
+pub trait FileSignerConfigTrait {
+    fn get_priv_key(&self) -> anyhow::Result<String>;
+    fn get_next_key(&self) -> anyhow::Result<Option<String>>;
+    fn get_retired_keys(&self) -> anyhow::Result<Vec<(SigningAlgorithm, String)>>;
+    fn get_priv_key_path(&self) -> String;
+    fn get_next_key_path(&self) -> String;
+    fn get_retired_key_path(&self, algorithm: SigningAlgorithm, kid: &str) -> String;
+    fn get_algorithm(&self) -> SigningAlgorithm;
+}
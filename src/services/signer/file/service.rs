@@ -0,0 +1,109 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{FileSignerConfig, FileSignerConfigTrait};
+use crate::services::signer::{KeyMaterial, KeyRing, SignerTrait};
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::utils::write;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::Jwk;
+use jsonwebtoken::Header;
+use serde_json::Value;
+use tokio::sync::Mutex;
+use tracing::info;
+
+pub struct FileSignerService {
+    config: FileSignerConfig,
+    keys: Mutex<KeyRing>,
+    rotation: Mutex<()>,
+}
+
+impl FileSignerService {
+    pub fn new(config: FileSignerConfig) -> anyhow::Result<FileSignerService> {
+        info!("Loading {} signing keys from file", config.get_algorithm());
+        let current = KeyMaterial::from_pem(&config.get_priv_key()?, config.get_algorithm())?;
+        // Without a staged key one is generated, it is only written to disk on rotation
+        let next = match config.get_next_key()? {
+            Some(pem) => KeyMaterial::from_pem(&pem, config.get_algorithm())?,
+            None => KeyMaterial::generate(config.get_algorithm())?,
+        };
+        let mut retired = Vec::new();
+        for (algorithm, pem) in config.get_retired_keys()? {
+            retired.push(KeyMaterial::from_pem(&pem, algorithm)?);
+        }
+        let keys = Mutex::new(KeyRing::new(current, next, retired));
+        Ok(FileSignerService {
+            config,
+            keys,
+            rotation: Mutex::new(()),
+        })
+    }
+}
+
+#[async_trait]
+impl SignerTrait for FileSignerService {
+    fn get_algorithm(&self) -> SigningAlgorithm {
+        self.config.get_algorithm()
+    }
+
+    async fn get_jwks(&self) -> anyhow::Result<Vec<Jwk>> {
+        Ok(self.keys.lock().await.get_jwks())
+    }
+
+    async fn get_did(&self) -> anyhow::Result<String> {
+        self.keys.lock().await.current().get_did()
+    }
+
+    async fn get_dids(&self) -> anyhow::Result<Vec<String>> {
+        self.keys.lock().await.get_dids()
+    }
+
+    async fn sign(&self, header: Header, claims: &Value) -> anyhow::Result<String> {
+        self.keys.lock().await.current().sign(header, claims)
+    }
+
+    async fn rotate(&self) -> anyhow::Result<Vec<Jwk>> {
+        // Only one rotation at a time, signing goes on with the current key meanwhile
+        let _rotation = self.rotation.lock().await;
+        let new_next = KeyMaterial::generate(self.config.get_algorithm())?;
+        let (retired_path, current, next) = {
+            let keys = self.keys.lock().await;
+            let (current, next) = (keys.current(), keys.next());
+            let path = self
+                .config
+                .get_retired_key_path(current.get_algorithm(), &current.get_kid());
+            (
+                path,
+                current.get_pem().to_string(),
+                next.get_pem().to_string(),
+            )
+        };
+
+        // Disk goes first so a failed write leaves the served keys untouched
+        write(&retired_path, &current)?;
+        write(&self.config.get_priv_key_path(), &next)?;
+        write(&self.config.get_next_key_path(), new_next.get_pem())?;
+
+        let mut keys = self.keys.lock().await;
+        let retired = keys.rotate(new_next);
+        info!("Retiring signing key {}", retired.get_kid());
+        info!("Signing with key {}", keys.current().get_kid());
+        Ok(keys.get_jwks())
+    }
+}
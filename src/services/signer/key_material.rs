@@ -0,0 +1,174 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use anyhow::bail;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, ThumbprintHash,
+};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use rsa::pkcs8::{EncodePrivateKey, LineEnding};
+use rsa::rand_core::OsRng;
+use rsa::RsaPrivateKey;
+use serde_json::Value;
+use tracing::error;
+
+// Private key loaded once, together with the public JWK that gets published
+pub struct KeyMaterial {
+    algorithm: SigningAlgorithm,
+    pem: String,
+    encoding_key: EncodingKey,
+    jwk: Jwk,
+}
+
+impl KeyMaterial {
+    pub fn from_pem(pem: &str, algorithm: SigningAlgorithm) -> anyhow::Result<Self> {
+        let parsed = match algorithm {
+            SigningAlgorithm::RS256 => EncodingKey::from_rsa_pem(pem.as_bytes()).and_then(|key| {
+                Jwk::from_encoding_key(&key, algorithm.to_jwt()).map(|jwk| (key, jwk))
+            }),
+            SigningAlgorithm::ES256 | SigningAlgorithm::ES384 => {
+                EncodingKey::from_ec_pem(pem.as_bytes()).and_then(|key| {
+                    Jwk::from_encoding_key(&key, algorithm.to_jwt()).map(|jwk| (key, jwk))
+                })
+            }
+            SigningAlgorithm::EdDSA => return Self::from_ed_pem(pem),
+        };
+
+        match parsed {
+            Ok((encoding_key, jwk)) => Ok(Self::new(algorithm, pem, encoding_key, jwk)),
+            Err(e) => {
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Error parsing {} private key: {}", algorithm, e),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    pub fn generate(algorithm: SigningAlgorithm) -> anyhow::Result<Self> {
+        let pem = match algorithm {
+            SigningAlgorithm::RS256 => {
+                RsaPrivateKey::new(&mut OsRng, 2048)?.to_pkcs8_pem(LineEnding::LF)?
+            }
+            SigningAlgorithm::ES256 => {
+                p256::SecretKey::random(&mut OsRng).to_pkcs8_pem(LineEnding::LF)?
+            }
+            SigningAlgorithm::ES384 => {
+                p384::SecretKey::random(&mut OsRng).to_pkcs8_pem(LineEnding::LF)?
+            }
+            SigningAlgorithm::EdDSA => {
+                ed25519_dalek::SigningKey::generate(&mut OsRng).to_pkcs8_pem(LineEnding::LF)?
+            }
+        };
+        Self::from_pem(&pem, algorithm)
+    }
+
+    pub fn get_algorithm(&self) -> SigningAlgorithm {
+        self.algorithm
+    }
+
+    pub fn get_jwk(&self) -> Jwk {
+        self.jwk.clone()
+    }
+
+    // did:jwk of the public key, so the DID resolves to the key that actually signs
+    pub fn get_did(&self) -> anyhow::Result<String> {
+        let jwk = serde_json::to_vec(&self.jwk)?;
+        Ok(format!("did:jwk:{}", URL_SAFE_NO_PAD.encode(jwk)))
+    }
+
+    pub fn get_kid(&self) -> String {
+        self.jwk.common.key_id.clone().unwrap_or_default()
+    }
+
+    pub fn get_pem(&self) -> &str {
+        &self.pem
+    }
+
+    pub fn sign(&self, mut header: Header, claims: &Value) -> anyhow::Result<String> {
+        header.alg = self.algorithm.to_jwt();
+        if header.kid.is_none() {
+            header.kid = self.jwk.common.key_id.clone();
+        }
+
+        match encode(&header, claims, &self.encoding_key) {
+            Ok(data) => Ok(data),
+            Err(e) => {
+                let error =
+                    Errors::format_new(BadFormat::Unknown, &format!("Error signing token: {}", e));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    fn from_ed_pem(pem: &str) -> anyhow::Result<Self> {
+        use ed25519_dalek::pkcs8::DecodePrivateKey;
+
+        let key = match ed25519_dalek::SigningKey::from_pkcs8_pem(pem) {
+            Ok(data) => data,
+            Err(e) => {
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Error parsing EdDSA private key: {}", e),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        // jsonwebtoken cannot derive an OKP jwk from an encoding key
+        let jwk = Jwk {
+            common: CommonParameters {
+                key_algorithm: Some(KeyAlgorithm::EdDSA),
+                ..Default::default()
+            },
+            algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: URL_SAFE_NO_PAD.encode(key.verifying_key().to_bytes()),
+            }),
+        };
+        let encoding_key = EncodingKey::from_ed_der(key.to_pkcs8_der()?.as_bytes());
+        Ok(Self::new(SigningAlgorithm::EdDSA, pem, encoding_key, jwk))
+    }
+
+    fn new(
+        algorithm: SigningAlgorithm,
+        pem: &str,
+        encoding_key: EncodingKey,
+        mut jwk: Jwk,
+    ) -> Self {
+        jwk.common.public_key_use = Some(PublicKeyUse::Signature);
+        jwk.common.key_id = Some(jwk.thumbprint(ThumbprintHash::SHA256));
+        Self {
+            algorithm,
+            pem: pem.to_string(),
+            encoding_key,
+            jwk,
+        }
+    }
+}
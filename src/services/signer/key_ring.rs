@@ -0,0 +1,68 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::services::signer::KeyMaterial;
+use jsonwebtoken::jwk::Jwk;
+
+// Keys published by a signer: the active one, the one staged to replace it and the retired ones
+pub struct KeyRing {
+    current: KeyMaterial,
+    next: KeyMaterial,
+    retired: Vec<KeyMaterial>,
+}
+
+impl KeyRing {
+    pub fn new(current: KeyMaterial, next: KeyMaterial, retired: Vec<KeyMaterial>) -> Self {
+        Self {
+            current,
+            next,
+            retired,
+        }
+    }
+
+    pub fn current(&self) -> &KeyMaterial {
+        &self.current
+    }
+
+    pub fn next(&self) -> &KeyMaterial {
+        &self.next
+    }
+
+    pub fn get_jwks(&self) -> Vec<Jwk> {
+        let mut keys = vec![self.current.get_jwk(), self.next.get_jwk()];
+        keys.extend(self.retired.iter().map(|key| key.get_jwk()));
+        keys
+    }
+
+    pub fn get_dids(&self) -> anyhow::Result<Vec<String>> {
+        let mut dids = vec![self.current.get_did()?, self.next.get_did()?];
+        for key in self.retired.iter() {
+            dids.push(key.get_did()?);
+        }
+        Ok(dids)
+    }
+
+    // The staged key starts signing and the old one stays published so its signatures still verify
+    pub fn rotate(&mut self, new_next: KeyMaterial) -> &KeyMaterial {
+        let next = std::mem::replace(&mut self.next, new_next);
+        let old = std::mem::replace(&mut self.current, next);
+        self.retired.insert(0, old);
+        &self.retired[0]
+    }
+}
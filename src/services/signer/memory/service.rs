@@ -0,0 +1,77 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{MemorySignerConfig, MemorySignerConfigTrait};
+use crate::services::signer::{KeyMaterial, KeyRing, SignerTrait};
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::Jwk;
+use jsonwebtoken::Header;
+use serde_json::Value;
+use tokio::sync::Mutex;
+use tracing::info;
+
+pub struct MemorySignerService {
+    config: MemorySignerConfig,
+    keys: Mutex<KeyRing>,
+}
+
+impl MemorySignerService {
+    pub fn new(config: MemorySignerConfig) -> anyhow::Result<MemorySignerService> {
+        info!(
+            "Generating ephemeral {} signing keys",
+            config.get_algorithm()
+        );
+        let current = KeyMaterial::generate(config.get_algorithm())?;
+        let next = KeyMaterial::generate(config.get_algorithm())?;
+        let keys = Mutex::new(KeyRing::new(current, next, Vec::new()));
+        Ok(MemorySignerService { config, keys })
+    }
+}
+
+#[async_trait]
+impl SignerTrait for MemorySignerService {
+    fn get_algorithm(&self) -> SigningAlgorithm {
+        self.config.get_algorithm()
+    }
+
+    async fn get_jwks(&self) -> anyhow::Result<Vec<Jwk>> {
+        Ok(self.keys.lock().await.get_jwks())
+    }
+
+    async fn get_did(&self) -> anyhow::Result<String> {
+        self.keys.lock().await.current().get_did()
+    }
+
+    async fn get_dids(&self) -> anyhow::Result<Vec<String>> {
+        self.keys.lock().await.get_dids()
+    }
+
+    async fn sign(&self, header: Header, claims: &Value) -> anyhow::Result<String> {
+        self.keys.lock().await.current().sign(header, claims)
+    }
+
+    async fn rotate(&self) -> anyhow::Result<Vec<Jwk>> {
+        let mut keys = self.keys.lock().await;
+        let retired = keys.rotate(KeyMaterial::generate(self.config.get_algorithm())?);
+        info!("Retiring signing key {}", retired.get_kid());
+        info!("Signing with key {}", keys.current().get_kid());
+        Ok(keys.get_jwks())
+    }
+}
@@ -0,0 +1,37 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use async_trait::async_trait;
+use jsonwebtoken::jwk::Jwk;
+use jsonwebtoken::Header;
+use serde_json::Value;
+
+#[async_trait]
+pub trait SignerTrait: Send + Sync + 'static {
+    fn get_algorithm(&self) -> SigningAlgorithm;
+    async fn get_jwks(&self) -> anyhow::Result<Vec<Jwk>>;
+    // DID of the key currently signing, used as "kid" and issuer id
+    async fn get_did(&self) -> anyhow::Result<String>;
+    // DIDs of every published key, credentials signed before a rotation keep theirs
+    async fn get_dids(&self) -> anyhow::Result<Vec<String>>;
+    // The signer owns "alg"; the rest of the header is kept as given
+    async fn sign(&self, header: Header, claims: &Value) -> anyhow::Result<String>;
+    async fn rotate(&self) -> anyhow::Result<Vec<Jwk>>;
+}
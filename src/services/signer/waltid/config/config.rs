@@ -0,0 +1,54 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::WaltIdSignerConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::types::wallet::WalletConfig;
+
+pub struct WaltIdSignerConfig {
+    ssi_wallet_config: WalletConfig,
+    algorithm: SigningAlgorithm,
+}
+
+impl From<CoreApplicationConfig> for WaltIdSignerConfig {
+    fn from(config: CoreApplicationConfig) -> WaltIdSignerConfig {
+        WaltIdSignerConfig {
+            ssi_wallet_config: config.ssi_wallet_config,
+            algorithm: config.signing_alg,
+        }
+    }
+}
+
+impl WaltIdSignerConfigTrait for WaltIdSignerConfig {
+    fn get_wallet_api_url(&self) -> String {
+        let data = self.ssi_wallet_config.clone();
+        match data.api_port {
+            Some(port) => {
+                format!("{}://{}:{}", data.api_protocol, data.api_url, port)
+            }
+            None => {
+                format!("{}://{}", data.api_protocol, data.api_url)
+            }
+        }
+    }
+    fn get_algorithm(&self) -> SigningAlgorithm {
+        self.algorithm
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{WaltIdSignerConfig, WaltIdSignerConfigTrait};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::client::ClientServiceTrait;
+use crate::services::signer::SignerTrait;
+use crate::services::wallet::WalletTrait;
+use crate::types::enums::request::Body;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use anyhow::bail;
+use async_trait::async_trait;
+use axum::http::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use axum::http::HeaderMap;
+use jsonwebtoken::jwk::{Jwk, ThumbprintHash};
+use jsonwebtoken::Header;
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{error, info};
+
+pub struct WaltIdSignerService {
+    config: WaltIdSignerConfig,
+    wallet: Arc<dyn WalletTrait>,
+    client: Arc<dyn ClientServiceTrait>,
+}
+
+impl WaltIdSignerService {
+    pub fn new(
+        config: WaltIdSignerConfig,
+        wallet: Arc<dyn WalletTrait>,
+        client: Arc<dyn ClientServiceTrait>,
+    ) -> WaltIdSignerService {
+        WaltIdSignerService {
+            config,
+            wallet,
+            client,
+        }
+    }
+
+    async fn key_url(&self, action: &str) -> anyhow::Result<String> {
+        let wallet = self.wallet.get_wallet().await?;
+        let key = self.wallet.get_key().await?;
+        Ok(format!(
+            "{}/wallet-api/wallet/{}/keys/{}/{}",
+            self.config.get_wallet_api_url(),
+            &wallet.id,
+            &key.key_id.id,
+            action
+        ))
+    }
+
+    async fn headers(&self) -> anyhow::Result<HeaderMap> {
+        let token = self.wallet.get_token().await?;
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, "application/json".parse()?);
+        headers.insert(ACCEPT, "application/json".parse()?);
+        headers.insert(AUTHORIZATION, format!("Bearer {}", token).parse()?);
+        Ok(headers)
+    }
+}
+
+#[async_trait]
+impl SignerTrait for WaltIdSignerService {
+    fn get_algorithm(&self) -> SigningAlgorithm {
+        self.config.get_algorithm()
+    }
+
+    async fn get_jwks(&self) -> anyhow::Result<Vec<Jwk>> {
+        info!("Exporting public key from web wallet");
+
+        let url = format!(
+            "{}?format=JWK&loadPrivateKey=false",
+            self.key_url("export").await?
+        );
+        let res = self.client.get(&url, Some(self.headers().await?)).await?;
+
+        match res.status().as_u16() {
+            200 => {
+                let mut jwk: Jwk = serde_json::from_str(&res.text().await?)?;
+                if jwk.common.key_id.is_none() {
+                    jwk.common.key_id = Some(jwk.thumbprint(ThumbprintHash::SHA256));
+                }
+                Ok(vec![jwk])
+            }
+            _ => {
+                let error = Errors::wallet_new(
+                    &url,
+                    "GET",
+                    res.status().as_u16(),
+                    "Petition to export key failed",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    async fn get_did(&self) -> anyhow::Result<String> {
+        self.wallet.get_did().await
+    }
+
+    async fn get_dids(&self) -> anyhow::Result<Vec<String>> {
+        Ok(vec![self.wallet.get_did().await?])
+    }
+
+    // The wallet builds its own protected header, so only the claims are sent and it gets checked after
+    async fn sign(&self, header: Header, claims: &Value) -> anyhow::Result<String> {
+        info!("Signing with web wallet key");
+
+        let url = self.key_url("sign").await?;
+        let res = self
+            .client
+            .post(
+                &url,
+                Some(self.headers().await?),
+                Body::Json(claims.clone()),
+            )
+            .await?;
+
+        match res.status().as_u16() {
+            200 => {
+                let jws = res.text().await?.trim_matches('"').to_string();
+                keeps_header(&header, &jws)?;
+                Ok(jws)
+            }
+            _ => {
+                let error = Errors::wallet_new(
+                    &url,
+                    "POST",
+                    res.status().as_u16(),
+                    "Petition to sign with wallet key failed",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    // Wallet keys are managed in the wallet itself
+    async fn rotate(&self) -> anyhow::Result<Vec<Jwk>> {
+        let error = Errors::not_impl_new(
+            "Key rotation",
+            "Key rotation is not supported when signing with the web wallet",
+        );
+        error!("{}", error.log());
+        bail!(error)
+    }
+}
+
+// Tokens whose "typ", "kid" or "x5c" differ from the requested ones would not verify as intended
+fn keeps_header(header: &Header, jws: &str) -> anyhow::Result<()> {
+    let signed = jsonwebtoken::decode_header(jws)?;
+    let kept = (header.typ.is_none() || header.typ == signed.typ)
+        && (header.kid.is_none() || header.kid == signed.kid)
+        && (header.x5c.is_none() || header.x5c == signed.x5c);
+    if !kept {
+        let error = Errors::not_impl_new(
+            "Wallet signing header",
+            "The web wallet does not sign with the requested typ, kid or x5c header",
+        );
+        error!("{}", error.log());
+        bail!(error)
+    }
+    Ok(())
+}
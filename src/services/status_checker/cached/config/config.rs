@@ -0,0 +1,45 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::CachedStatusCheckerConfigTrait;
+use crate::config::CoreApplicationConfig;
+use crate::types::enums::unreachable_policy::UnreachablePolicy;
+
+pub struct CachedStatusCheckerConfig {
+    unreachable_policy: UnreachablePolicy,
+    clock_skew: u64,
+}
+
+impl From<CoreApplicationConfig> for CachedStatusCheckerConfig {
+    fn from(config: CoreApplicationConfig) -> CachedStatusCheckerConfig {
+        CachedStatusCheckerConfig {
+            unreachable_policy: config.status_unreachable_policy,
+            clock_skew: config.clock_skew,
+        }
+    }
+}
+
+impl CachedStatusCheckerConfigTrait for CachedStatusCheckerConfig {
+    fn get_unreachable_policy(&self) -> UnreachablePolicy {
+        self.unreachable_policy
+    }
+    fn get_clock_skew(&self) -> u64 {
+        self.clock_skew
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::config::{CachedStatusCheckerConfig, CachedStatusCheckerConfigTrait};
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::client::ClientServiceTrait;
+use crate::services::resolver::DidResolverTrait;
+use crate::services::status_checker::StatusCheckerTrait;
+use crate::types::enums::errors::BadFormat;
+use crate::types::enums::unreachable_policy::UnreachablePolicy;
+use crate::types::status_list::STATUS_LIST_TTL;
+use crate::types::vcs::BitstringStatusListEntry;
+use crate::utils::{decode_status_list, get_claim, get_status_bit, validate_token};
+use anyhow::bail;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info, warn};
+
+#[derive(Clone)]
+struct StatusList {
+    issuer: String,
+    purpose: String,
+    bits: Vec<u8>,
+}
+
+pub struct CachedStatusCheckerService {
+    config: CachedStatusCheckerConfig,
+    client: Arc<dyn ClientServiceTrait>,
+    resolver: Arc<dyn DidResolverTrait>,
+    cache: Mutex<HashMap<String, (i64, StatusList)>>,
+}
+
+impl CachedStatusCheckerService {
+    pub fn new(
+        config: CachedStatusCheckerConfig,
+        client: Arc<dyn ClientServiceTrait>,
+        resolver: Arc<dyn DidResolverTrait>,
+    ) -> CachedStatusCheckerService {
+        CachedStatusCheckerService {
+            config,
+            client,
+            resolver,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, uri: &str) -> Option<StatusList> {
+        let now = Utc::now().timestamp();
+        let mut cache = self.cache.lock().unwrap();
+        cache.retain(|_, (expires_at, _)| *expires_at > now);
+        cache.get(uri).map(|(_, list)| list.clone())
+    }
+
+    async fn download(&self, uri: &str) -> anyhow::Result<String> {
+        info!("Fetching status list {}", uri);
+        let res = self.client.get(uri, None).await?;
+        match res.status().as_u16() {
+            200 => Ok(res.text().await?.trim().trim_matches('"').to_string()),
+            code => {
+                let error = Errors::petition_new(
+                    uri,
+                    "GET",
+                    Some(code),
+                    "Status list could not be retrieved",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    async fn load(&self, uri: &str, jwt: &str) -> anyhow::Result<StatusList> {
+        let (token, issuer) = validate_token::<Value>(
+            jwt,
+            None,
+            self.resolver.as_ref(),
+            self.config.get_clock_skew(),
+        )
+        .await?;
+        let vc = token.claims.get("vc").unwrap_or(&token.claims);
+        let subject = &vc["credentialSubject"];
+        let list = StatusList {
+            issuer,
+            purpose: get_claim(subject, vec!["statusPurpose"])?,
+            bits: decode_status_list(&get_claim(subject, vec!["encodedList"])?)?,
+        };
+
+        // The ttl is given in milliseconds, a list is never kept beyond its own validity
+        let now = Utc::now().timestamp();
+        let ttl = subject
+            .get("ttl")
+            .and_then(Value::as_u64)
+            .unwrap_or(STATUS_LIST_TTL);
+        let valid_until = vc
+            .get("validUntil")
+            .and_then(Value::as_str)
+            .and_then(|until| DateTime::parse_from_rfc3339(until).ok())
+            .map(|until| until.timestamp());
+        if valid_until.is_some_and(|until| until <= now) {
+            let error = Errors::security_new(&format!("Status list {} has expired", uri));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        // exp was already checked along with the signature, here it only bounds the caching
+        let expires_at = [valid_until, token.claims.get("exp").and_then(Value::as_i64)]
+            .into_iter()
+            .flatten()
+            .fold(now + (ttl / 1000) as i64, i64::min);
+
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(uri.to_string(), (expires_at, list.clone()));
+        Ok(list)
+    }
+}
+
+#[async_trait]
+impl StatusCheckerTrait for CachedStatusCheckerService {
+    async fn get_status_list(
+        &self,
+        uri: &str,
+        purpose: &str,
+        issuer: &str,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let list = match self.cached(uri) {
+            Some(list) => list,
+            None => match self.download(uri).await {
+                Ok(jwt) => self.load(uri, &jwt).await?,
+                Err(e) => match self.config.get_unreachable_policy() {
+                    UnreachablePolicy::Accept => {
+                        warn!("Status list {} is unreachable, skipping it -> {}", uri, e);
+                        return Ok(None);
+                    }
+                    UnreachablePolicy::Reject => return Err(e),
+                },
+            },
+        };
+
+        if list.issuer != issuer {
+            let error =
+                Errors::security_new(&format!("Status list {} is not issued by {}", uri, issuer));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        if list.purpose != purpose {
+            let error = Errors::security_new(&format!(
+                "Status list {} is a {} list, not a {} one",
+                uri, list.purpose, purpose
+            ));
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(Some(list.bits))
+    }
+
+    async fn check_status(&self, credential_status: &Value, issuer: &str) -> anyhow::Result<()> {
+        let entries = match credential_status {
+            Value::Array(entries) => entries.iter().collect(),
+            entry => vec![entry],
+        };
+
+        for entry in entries {
+            // Other status mechanisms and purposes say nothing about validity
+            let known_type = matches!(
+                entry.get("type").and_then(Value::as_str),
+                Some("BitstringStatusListEntry" | "StatusList2021Entry")
+            );
+            let known_purpose = matches!(
+                entry.get("statusPurpose").and_then(Value::as_str),
+                Some("revocation" | "suspension")
+            );
+            if !known_type || !known_purpose {
+                info!("Skipping unsupported credential status entry");
+                continue;
+            }
+            let entry: BitstringStatusListEntry = match serde_json::from_value(entry.clone()) {
+                Ok(data) => data,
+                Err(e) => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        &format!("Credential status entry is malformed -> {}", e),
+                    );
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            };
+            let index: i64 = match entry.status_list_index.parse() {
+                Ok(data) => data,
+                Err(_) => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        &format!(
+                            "Status list index {} is not a number",
+                            entry.status_list_index
+                        ),
+                    );
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            };
+
+            let purpose = entry.status_purpose.to_string();
+            let bits = match self
+                .get_status_list(&entry.status_list_credential, &purpose, issuer)
+                .await?
+            {
+                Some(bits) => bits,
+                None => continue,
+            };
+            if get_status_bit(&bits, index)? {
+                let error = Errors::security_new(&format!(
+                    "Credential status {} is set, the credential is under {}",
+                    entry.id, purpose
+                ));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+        info!("Credential status is valid");
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+#[async_trait]
+pub trait StatusCheckerTrait: Send + Sync + 'static {
+    // Status list bits of the given purpose, the list has to be issued by the credential issuer
+    // None when the list cannot be reached and the policy lets the credential through
+    async fn get_status_list(
+        &self,
+        uri: &str,
+        purpose: &str,
+        issuer: &str,
+    ) -> anyhow::Result<Option<Vec<u8>>>;
+    // Fails when any revocation or suspension entry of the credentialStatus is set
+    async fn check_status(&self, credential_status: &Value, issuer: &str) -> anyhow::Result<()>;
+}
@@ -19,18 +19,41 @@
 
 use super::BasicVerifierConfigTrait;
 use crate::config::{CoreApplicationConfig, CoreApplicationConfigTrait};
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::client_id_scheme::ClientIdScheme;
+use crate::types::enums::query_language::QueryLanguage;
 use crate::types::host::HostConfig;
+use crate::utils::read;
+use std::sync::Arc;
 
 pub struct BasicVerifierConfig {
     host: HostConfig,
     is_local: bool,
+    registry: Arc<CredentialRegistry>,
     api_path: String,
+    query_language: QueryLanguage,
+    client_id_scheme: ClientIdScheme,
+    encrypt_response: bool,
+    session_lifetime: u64,
+    clock_skew: u64,
+    keys_path: String,
 }
 
 impl From<CoreApplicationConfig> for BasicVerifierConfig {
     fn from(config: CoreApplicationConfig) -> BasicVerifierConfig {
         let api_path = config.get_api_path();
-        BasicVerifierConfig { host: config.host, is_local: config.is_local, api_path }
+        BasicVerifierConfig {
+            host: config.host,
+            is_local: config.is_local,
+            registry: config.credential_registry,
+            api_path,
+            query_language: config.vp_query_language,
+            client_id_scheme: config.vp_client_id_scheme,
+            encrypt_response: config.vp_encrypt_response,
+            session_lifetime: config.vp_session_lifetime,
+            clock_skew: config.clock_skew,
+            keys_path: config.keys_path,
+        }
     }
 }
 
@@ -47,6 +70,10 @@ impl BasicVerifierConfigTrait for BasicVerifierConfig {
         }
     }
 
+    fn get_host_name(&self) -> String {
+        self.host.url.clone()
+    }
+
     fn get_host(&self) -> String {
         let host = self.host.clone();
         match host.port {
@@ -65,4 +92,26 @@ impl BasicVerifierConfigTrait for BasicVerifierConfig {
     fn get_api_path(&self) -> String {
         self.api_path.clone()
     }
+    fn get_registry(&self) -> Arc<CredentialRegistry> {
+        self.registry.clone()
+    }
+    fn get_query_language(&self) -> QueryLanguage {
+        self.query_language
+    }
+    fn get_client_id_scheme(&self) -> ClientIdScheme {
+        self.client_id_scheme
+    }
+    fn is_response_encrypted(&self) -> bool {
+        self.encrypt_response
+    }
+    fn get_session_lifetime(&self) -> u64 {
+        self.session_lifetime
+    }
+    fn get_clock_skew(&self) -> u64 {
+        self.clock_skew
+    }
+    fn get_cert(&self) -> anyhow::Result<String> {
+        let path = format!("{}/cert.pem", self.keys_path);
+        read(&path)
+    }
 }
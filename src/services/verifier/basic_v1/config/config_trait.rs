@@ -17,9 +17,22 @@
  *
  */
 
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::client_id_scheme::ClientIdScheme;
+use crate::types::enums::query_language::QueryLanguage;
+use std::sync::Arc;
+
 pub trait BasicVerifierConfigTrait {
     fn get_host_without_protocol(&self) -> String;
+    fn get_host_name(&self) -> String;
     fn get_host(&self) -> String;
     fn is_local(&self) -> bool;
     fn get_api_path(&self) -> String;
+    fn get_registry(&self) -> Arc<CredentialRegistry>;
+    fn get_query_language(&self) -> QueryLanguage;
+    fn get_client_id_scheme(&self) -> ClientIdScheme;
+    fn is_response_encrypted(&self) -> bool;
+    fn get_session_lifetime(&self) -> u64;
+    fn get_clock_skew(&self) -> u64;
+    fn get_cert(&self) -> anyhow::Result<String>;
 }
@@ -19,3 +19,6 @@
 pub mod config;
 mod service;
 pub use service::BasicVerifierService;
+
+#[cfg(test)]
+mod tests;
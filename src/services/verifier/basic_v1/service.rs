@@ -19,36 +19,366 @@
 
 use super::super::VerifierTrait;
 use super::config::{BasicVerifierConfig, BasicVerifierConfigTrait};
-use crate::data::entities::verification;
+use crate::data::entities::{trusted_issuer, verification};
 use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::resolver::DidResolverTrait;
+use crate::services::signer::SignerTrait;
+use crate::services::status_checker::StatusCheckerTrait;
+use crate::types::dcql::DcqlQuery;
+use crate::types::enums::client_id_scheme::ClientIdScheme;
 use crate::types::enums::errors::BadFormat;
-use crate::types::enums::vc_type::VcType;
-use crate::types::vcs::VPDef;
-use crate::utils::{get_claim, get_opt_claim, split_did};
+use crate::types::enums::query_language::QueryLanguage;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::types::vcs::{
+    DescriptorMap, InputDescriptor, InputDescriptorFormat, PresentationSubmission, SdJwt,
+    SubmissionRequirement, VPDef,
+};
+use crate::types::verifying::{ClientMetadata, RequestObject, VerifyPayload};
+use crate::utils::{
+    decrypt_jwe, ec_public_jwk, get_claim, get_opt_claim, json_path, split_did, TtlSet,
+};
 use anyhow::bail;
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use async_trait::async_trait;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use base64::Engine;
 use chrono::{DateTime, Utc};
+use jsonwebtoken::errors::ErrorKind;
 use jsonwebtoken::jwk::Jwk;
-use jsonwebtoken::{TokenData, Validation};
-use serde_json::Value;
-use std::collections::HashSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Header, TokenData, Validation};
+use rsa::rand_core::OsRng;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use tracing::{error, info};
 use urlencoding::encode;
+use x509_parser::extensions::GeneralName;
+use x509_parser::pem::parse_x509_pem;
 
 pub struct BasicVerifierService {
     config: BasicVerifierConfig,
+    resolver: Arc<dyn DidResolverTrait>,
+    signer: Arc<dyn SignerTrait>,
+    status: Arc<dyn StatusCheckerTrait>,
+    used_states: TtlSet,
+    vp_jtis: TtlSet,
 }
 
 impl BasicVerifierService {
-    pub fn new(config: BasicVerifierConfig) -> BasicVerifierService {
-        BasicVerifierService { config }
+    pub fn new(
+        config: BasicVerifierConfig,
+        resolver: Arc<dyn DidResolverTrait>,
+        signer: Arc<dyn SignerTrait>,
+        status: Arc<dyn StatusCheckerTrait>,
+    ) -> BasicVerifierService {
+        BasicVerifierService {
+            config,
+            resolver,
+            signer,
+            status,
+            used_states: TtlSet::new(),
+            vp_jtis: TtlSet::new(),
+        }
+    }
+
+    // exp and nbf are checked whenever present, with some tolerance for clock drift
+    fn validation(&self, alg: Algorithm) -> Validation {
+        let mut val = Validation::new(alg);
+        val.required_spec_claims = HashSet::new();
+        val.validate_exp = true;
+        val.validate_nbf = true;
+        val.leeway = self.config.get_clock_skew();
+        val
+    }
+
+    // Tokens signed by the holder for a session cannot predate it
+    fn validate_iat(
+        &self,
+        token: &TokenData<Value>,
+        session: Option<&verification::Model>,
+    ) -> anyhow::Result<()> {
+        let iat = match token.claims.get("iat").and_then(Value::as_i64) {
+            Some(iat) => iat,
+            None => return Ok(()),
+        };
+        let skew = self.config.get_clock_skew() as i64;
+        if iat > Utc::now().timestamp() + skew {
+            let error = Errors::security_new("Token is issued in the future");
+            error!("{}", error.log());
+            bail!(error);
+        }
+        if session.is_some_and(|model| iat < model.created_at.and_utc().timestamp() - skew) {
+            let error = Errors::security_new("Token was issued before the verification started");
+            error!("{}", error.log());
+            bail!(error);
+        }
+        Ok(())
+    }
+
+    // A jti is remembered for as long as its token could still be accepted
+    fn validate_jti(&self, token: &TokenData<Value>) -> anyhow::Result<()> {
+        let jti = match get_opt_claim(&token.claims, vec!["jti"])? {
+            Some(jti) => jti,
+            None => return Ok(()),
+        };
+        let now = Utc::now().timestamp();
+        let lifetime = self.config.get_session_lifetime() as i64;
+        let ttl = match token.claims.get("exp").and_then(Value::as_i64) {
+            Some(exp) => (exp - now).max(lifetime),
+            None => lifetime,
+        };
+        if !self
+            .vp_jtis
+            .insert(&jti, ttl + self.config.get_clock_skew() as i64)
+        {
+            let error =
+                Errors::security_new(&format!("VP token {} has already been presented", jti));
+            error!("{}", error.log());
+            bail!(error);
+        }
+        Ok(())
+    }
+
+    fn verifier_url(&self) -> String {
+        let host_url = format!(
+            "{}{}/verifier",
+            self.config.get_host(),
+            self.config.get_api_path()
+        );
+        match self.config.is_local() {
+            true => host_url.replace("127.0.0.1", "host.docker.internal"),
+            false => host_url,
+        }
+    }
+
+    // The certificate in keys_path has to name this host among its DNS SANs
+    fn x509_san_dns(&self) -> anyhow::Result<(String, Vec<String>)> {
+        let pem = self.config.get_cert()?;
+        let (_, pem) = parse_x509_pem(pem.as_bytes())?;
+        let cert = pem.parse_x509()?;
+        let host = self.config.get_host_name();
+        let san_dns = match cert.subject_alternative_name()? {
+            Some(san) => san
+                .value
+                .general_names
+                .iter()
+                .any(|name| matches!(name, GeneralName::DNSName(dns) if *dns == host)),
+            None => false,
+        };
+        if !san_dns {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                &format!("Certificate has no DNS SAN for '{}'", host),
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok((host, vec![STANDARD.encode(&pem.contents)]))
+    }
+
+    fn response_key(&self, model: &verification::Model) -> anyhow::Result<Option<p256::SecretKey>> {
+        match &model.response_key {
+            Some(key) => Ok(Some(p256::SecretKey::from_slice(
+                &URL_SAFE_NO_PAD.decode(key)?,
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    fn response_mode(&self, model: &verification::Model) -> &'static str {
+        match model.response_key.is_some() {
+            true => "direct_post.jwt",
+            false => "direct_post",
+        }
+    }
+
+    // Signed requests name the verifier by its client id, otherwise the response uri is the audience
+    async fn expected_audience(&self, model: &verification::Model) -> anyhow::Result<String> {
+        match self.config.get_client_id_scheme() {
+            ClientIdScheme::RedirectUri => {
+                Ok(format!("{}/verify/{}", self.verifier_url(), model.state))
+            }
+            _ => self.get_client_id(model).await,
+        }
+    }
+
+    // The public half of the session key is advertised for the wallet to encrypt its response
+    fn client_metadata(&self, model: &verification::Model) -> anyhow::Result<ClientMetadata> {
+        let algs: Vec<String> = SigningAlgorithm::all()
+            .iter()
+            .map(|alg| alg.to_string())
+            .collect();
+        let mut metadata = ClientMetadata {
+            vp_formats: json!({
+                "jwt_vp_json": { "alg": algs },
+                "jwt_vc_json": { "alg": algs },
+                "dc+sd-jwt": { "sd-jwt_alg_values": algs, "kb-jwt_alg_values": algs }
+            }),
+            jwks: None,
+            authorization_encrypted_response_alg: None,
+            authorization_encrypted_response_enc: None,
+        };
+        if let Some(secret) = self.response_key(model)? {
+            let mut jwk = ec_public_jwk(&secret);
+            jwk["use"] = json!("enc");
+            jwk["alg"] = json!("ECDH-ES");
+            jwk["kid"] = json!(model.state);
+            metadata.jwks = Some(json!({ "keys": [jwk] }));
+            metadata.authorization_encrypted_response_alg = Some("ECDH-ES".to_string());
+            metadata.authorization_encrypted_response_enc = Some("A256GCM".to_string());
+        }
+        Ok(metadata)
+    }
+
+    // Follows a descriptor map entry, nested paths included, down to one of the verified credentials
+    fn resolve_descriptor<'a>(
+        &self,
+        entry: &DescriptorMap,
+        scope: &Value,
+        verified: &'a HashMap<String, Value>,
+    ) -> Result<(String, &'a Value), String> {
+        let mut nodes = json_path(scope, &entry.path).map_err(|e| e.to_string())?;
+        // JWT VPs keep the presentation under the "vp" claim
+        if nodes.is_empty() {
+            if let Some(vp) = scope.get("vp") {
+                nodes = json_path(vp, &entry.path).map_err(|e| e.to_string())?;
+            }
+        }
+        let node = match nodes.as_slice() {
+            [node] => *node,
+            [] => return Err(format!("path {} selects nothing", entry.path)),
+            _ => return Err(format!("path {} selects more than one element", entry.path)),
+        };
+        if let Some(nested) = &entry.path_nested {
+            return self.resolve_descriptor(nested, node, verified);
+        }
+        match node.as_str().and_then(|vc| verified.get(vc)) {
+            Some(claims) => Ok((entry.format.clone(), claims)),
+            None => Err(format!(
+                "path {} does not select a presented credential",
+                entry.path
+            )),
+        }
+    }
+
+    fn check_descriptor(
+        &self,
+        descriptor: &InputDescriptor,
+        format: &str,
+        claims: &Value,
+    ) -> Vec<String> {
+        let mut reasons = Vec::new();
+        if !descriptor.format.accepts(format) {
+            reasons.push(format!("format {} is not accepted", format));
+        }
+        if descriptor.constraints.limit_disclosure.as_deref() == Some("required")
+            && !InputDescriptorFormat::is_sd_jwt(format)
+        {
+            reasons.push(format!(
+                "limit_disclosure is required but {} discloses every claim",
+                format
+            ));
+        }
+        for field in &descriptor.constraints.fields {
+            let values = field.path.iter().find_map(|path| {
+                json_path(claims, path)
+                    .ok()
+                    .filter(|values| !values.is_empty())
+            });
+            let values = match values {
+                Some(values) => values,
+                None => {
+                    if !field.optional.unwrap_or(false) {
+                        reasons.push(format!("no value at {}", field.path.join(" | ")));
+                    }
+                    continue;
+                }
+            };
+            let filter = match serde_json::to_value(&field.filter)
+                .ok()
+                .and_then(|filter| jsonschema::validator_for(&filter).ok())
+            {
+                Some(validator) => validator,
+                None => {
+                    reasons.push(format!(
+                        "filter of {} is not a valid schema",
+                        field.path.join(" | ")
+                    ));
+                    continue;
+                }
+            };
+            // Type filters are usually written for the elements of the "type" array
+            let matches = values.iter().any(|value| match value {
+                Value::Array(items) => {
+                    filter.is_valid(value) || items.iter().any(|item| filter.is_valid(item))
+                }
+                _ => filter.is_valid(value),
+            });
+            if !matches {
+                reasons.push(format!(
+                    "value at {} does not match the filter",
+                    field.path.join(" | ")
+                ));
+            }
+        }
+        reasons
+    }
+
+    fn meets_requirement(
+        &self,
+        requirement: &SubmissionRequirement,
+        vpd: &VPDef,
+        failures: &HashMap<String, Vec<String>>,
+    ) -> bool {
+        let (satisfied, total) = match (&requirement.from, &requirement.from_nested) {
+            (Some(group), _) => {
+                let members: Vec<&InputDescriptor> = vpd
+                    .input_descriptors
+                    .iter()
+                    .filter(|descriptor| {
+                        descriptor
+                            .group
+                            .as_ref()
+                            .is_some_and(|groups| groups.contains(group))
+                    })
+                    .collect();
+                let satisfied = members
+                    .iter()
+                    .filter(|descriptor| !failures.contains_key(&descriptor.id))
+                    .count();
+                (satisfied, members.len())
+            }
+            (None, Some(nested)) => {
+                let satisfied = nested
+                    .iter()
+                    .filter(|nested| self.meets_requirement(nested, vpd, failures))
+                    .count();
+                (satisfied, nested.len())
+            }
+            (None, None) => (0, 0),
+        };
+        requirement.accepts(satisfied, total)
     }
 }
 
+// VCDM 1.1 wraps the credential in a "vc" claim, VCDM 2.0 puts it at the top level
+fn vc_claims(claims: &Value) -> &Value {
+    claims.get("vc").unwrap_or(claims)
+}
+
+// jti for VC-JWT and SD-JWT VCs, the credential id for VCDM 2.0 ones
+fn credential_id(claims: &Value) -> Option<String> {
+    claims
+        .get("jti")
+        .or_else(|| vc_claims(claims).get("id"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+#[async_trait]
 impl VerifierTrait for BasicVerifierService {
-    fn start_vp(&self, id: &str, vc_type: VcType) -> anyhow::Result<verification::NewModel> {
+    fn start_vp(&self, id: &str, vc_type: &str) -> anyhow::Result<verification::NewModel> {
         info!("Managing OIDC4VP");
+        let vc_type = self.config.get_registry().get(vc_type)?.name.clone();
         let host_url = self.config.get_host();
         let host_url = match self.config.is_local() {
             true => host_url.replace("127.0.0.1", "host.docker.internal"),
@@ -56,116 +386,591 @@ impl VerifierTrait for BasicVerifierService {
         };
 
         let client_id = format!("{}/verify", &host_url);
-        let new_verification_model =
-            verification::NewModel { id: id.to_string(), audience: client_id, vc_type: vc_type.to_string() };
+        // Ephemeral key the wallet encrypts its response to, it lives as long as the session
+        let response_key = match self.config.is_response_encrypted() {
+            true => Some(URL_SAFE_NO_PAD.encode(p256::SecretKey::random(&mut OsRng).to_bytes())),
+            false => None,
+        };
+        let new_verification_model = verification::NewModel {
+            id: id.to_string(),
+            audience: client_id,
+            vc_type,
+            response_key,
+        };
 
         Ok(new_verification_model)
     }
 
-    fn generate_verification_uri(&self, model: verification::Model) -> String {
-        info!("Generating verification exchange URI");
+    fn is_expired(&self, model: &verification::Model) -> bool {
+        let lifetime = chrono::Duration::seconds(self.config.get_session_lifetime() as i64);
+        model.created_at + lifetime < Utc::now().naive_utc()
+    }
 
-        let host_url = self.config.get_host();
-        let host_url = format!("{}{}/verifier", host_url, self.config.get_api_path());
-        let host_url = match self.config.is_local() {
-            true => host_url.replace("127.0.0.1", "host.docker.internal"),
-            false => host_url,
+    fn claim_state(&self, model: &verification::Model) -> anyhow::Result<()> {
+        // Guards against concurrent responses racing for the same pending state
+        let ttl = (self.config.get_session_lifetime() + self.config.get_clock_skew()) as i64;
+        if !self.used_states.insert(&model.state, ttl) {
+            let error = Errors::security_new(&format!(
+                "Verification {} has already been used",
+                model.state
+            ));
+            error!("{}", error.log());
+            bail!(error);
+        }
+        Ok(())
+    }
+
+    async fn get_client_id(&self, model: &verification::Model) -> anyhow::Result<String> {
+        let scheme = self.config.get_client_id_scheme();
+        let id = match scheme {
+            ClientIdScheme::RedirectUri => model.audience.clone(),
+            ClientIdScheme::X509SanDns => self.x509_san_dns()?.0,
+            ClientIdScheme::Did => self.signer.get_did().await?,
         };
+        Ok(scheme.client_id(&id))
+    }
 
+    async fn generate_verification_uri(
+        &self,
+        model: verification::Model,
+    ) -> anyhow::Result<String> {
+        info!("Generating verification exchange URI");
+
+        let host_url = self.verifier_url();
         let base_url = "openid4vp://authorize";
+        let client_id_scheme = self.config.get_client_id_scheme();
+
+        // Signed requests travel by reference, the wallet fetches them from request_uri
+        if client_id_scheme != ClientIdScheme::RedirectUri {
+            let client_id = self.get_client_id(&model).await?;
+            let request_uri = format!("{}/request/{}", &host_url, model.state);
+            let uri = format!(
+                "{}?client_id={}&request_uri={}",
+                base_url,
+                encode(&client_id),
+                encode(&request_uri)
+            );
+            info!("Uri generated successfully: {}", uri);
+            return Ok(uri);
+        }
+
         let encoded_client_id = encode(&model.audience);
-        let presentation_definition_uri = format!("{}/pd/{}", &host_url, model.state);
-        let encoded_presentation_definition_uri = encode(&presentation_definition_uri);
+        // DCQL queries travel by value, Presentation Exchange definitions by reference
+        let query = match self.config.get_query_language() {
+            QueryLanguage::Dcql => {
+                let dcql_query = serde_json::to_string(&self.generate_dcql(&model)?)?;
+                format!("dcql_query={}", encode(&dcql_query))
+            }
+            QueryLanguage::Pex => {
+                let presentation_definition_uri = format!("{}/pd/{}", &host_url, model.state);
+                format!(
+                    "presentation_definition_uri={}",
+                    encode(&presentation_definition_uri)
+                )
+            }
+        };
         let response_uri = format!("{}/verify/{}", &host_url, model.state);
         let encoded_response_uri = encode(&response_uri);
         let response_type = "vp_token";
-        let response_mode = "direct_post";
-        let client_id_scheme = "redirect_uri";
+        let response_mode = self.response_mode(&model);
 
-        // TODO let client_metadata = r#"{"authorization_encrypted_response_alg":"ECDH-ES","authorization_encrypted_response_enc":"A256GCM"}"#;
-
-        let uri = format!("{}?response_type={}&client_id={}&response_mode={}&presentation_definition_uri={}&client_id_scheme={}&nonce={}&response_uri={}",
+        let mut uri = format!("{}?response_type={}&client_id={}&response_mode={}&{}&client_id_scheme={}&nonce={}&response_uri={}",
                           base_url,
                           response_type,
                           encoded_client_id,
                           response_mode,
-                          encoded_presentation_definition_uri,
+                          query,
                           client_id_scheme,
                           model.nonce,
                           encoded_response_uri);
+        if model.response_key.is_some() {
+            let client_metadata = serde_json::to_string(&self.client_metadata(&model)?)?;
+            uri = format!("{}&client_metadata={}", uri, encode(&client_metadata));
+        }
         info!("Uri generated successfully: {}", uri);
 
-        uri
+        Ok(uri)
+    }
+
+    async fn generate_request_object(&self, model: verification::Model) -> anyhow::Result<String> {
+        info!("Generating request object");
+
+        let client_id_scheme = self.config.get_client_id_scheme();
+        let mut header = Header {
+            typ: Some("oauth-authz-req+jwt".to_string()),
+            ..Default::default()
+        };
+        match client_id_scheme {
+            ClientIdScheme::RedirectUri => {
+                let error = Errors::missing_resource_new(
+                    &model.state,
+                    "Requests using the redirect_uri scheme are passed by value",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+            ClientIdScheme::X509SanDns => header.x5c = Some(self.x509_san_dns()?.1),
+            ClientIdScheme::Did => header.kid = Some(self.signer.get_did().await?),
+        };
+        let client_id = self.get_client_id(&model).await?;
+
+        let (presentation_definition, dcql_query) = match self.config.get_query_language() {
+            QueryLanguage::Pex => (Some(self.generate_vpd(model.clone())?), None),
+            QueryLanguage::Dcql => (None, Some(self.generate_dcql(&model)?)),
+        };
+        let client_metadata = self.client_metadata(&model)?;
+        let request = RequestObject {
+            iss: client_id.clone(),
+            aud: "https://self-issued.me/v2".to_string(),
+            iat: Utc::now().timestamp(),
+            exp: model.created_at.and_utc().timestamp() + self.config.get_session_lifetime() as i64,
+            client_id,
+            response_type: "vp_token".to_string(),
+            response_mode: self.response_mode(&model).to_string(),
+            response_uri: format!("{}/verify/{}", self.verifier_url(), model.state),
+            nonce: model.nonce,
+            state: model.state,
+            presentation_definition,
+            dcql_query,
+            client_metadata,
+        };
+
+        self.signer
+            .sign(header, &serde_json::to_value(&request)?)
+            .await
+    }
+
+    fn read_response(
+        &self,
+        model: &verification::Model,
+        payload: VerifyPayload,
+    ) -> anyhow::Result<(String, Option<String>)> {
+        info!("Reading authorization response");
+
+        let secret = match self.response_key(model)? {
+            Some(secret) => secret,
+            None => match payload.vp_token {
+                Some(vp_token) => return Ok((vp_token, payload.presentation_submission)),
+                None => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        "Response does not contain a vp_token",
+                    );
+                    error!("{}", error.log());
+                    bail!(error)
+                }
+            },
+        };
+        let jwe = match payload.response {
+            Some(data) => data,
+            None => {
+                let error = Errors::security_new(&format!(
+                    "Verification {} expects an encrypted response",
+                    model.state
+                ));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+
+        let response: Value = serde_json::from_slice(&decrypt_jwe(&jwe, &secret)?)?;
+        if response
+            .get("state")
+            .and_then(Value::as_str)
+            .is_some_and(|state| state != model.state)
+        {
+            let error = Errors::security_new("Response state does not match the verification");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        // Encrypted responses carry JSON values where form posts carry strings
+        let as_string = |value: &Value| match value {
+            Value::String(data) => data.clone(),
+            other => other.to_string(),
+        };
+        let vp_token = match response.get("vp_token") {
+            Some(data) => as_string(data),
+            None => {
+                let error =
+                    Errors::format_new(BadFormat::Received, "Response does not contain a vp_token");
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        let presentation_submission = response.get("presentation_submission").map(as_string);
+        Ok((vp_token, presentation_submission))
     }
 
-    fn generate_vpd(&self, ver_model: verification::Model) -> VPDef {
+    fn generate_vpd(&self, ver_model: verification::Model) -> anyhow::Result<VPDef> {
         info!("Generating an vp definition");
-        VPDef::new(ver_model.id, ver_model.vc_type)
+        let registry = self.config.get_registry();
+        let cred_type = registry.get(&ver_model.vc_type)?;
+        Ok(VPDef::new(ver_model.id, cred_type))
+    }
+
+    fn generate_dcql(&self, ver_model: &verification::Model) -> anyhow::Result<DcqlQuery> {
+        info!("Generating a DCQL query");
+        let registry = self.config.get_registry();
+        Ok(registry.policy(&ver_model.vc_type)?.to_dcql())
     }
 
-    fn verify_all(&self, ver_model: &mut verification::Model, vp_token: String) -> anyhow::Result<()> {
+    // Credentials this authority signed are trusted for every type it issues, even with an empty list
+    async fn own_trusted_issuers(&self) -> anyhow::Result<Vec<trusted_issuer::Model>> {
+        let registry = self.config.get_registry();
+        let vc_types: Vec<String> = registry
+            .credentials
+            .iter()
+            .map(|cred| cred.name.clone())
+            .collect();
+        let created_at = Utc::now().naive_utc();
+        let entries = self
+            .signer
+            .get_dids()
+            .await?
+            .into_iter()
+            .map(|did| trusted_issuer::Model {
+                id: did.clone(),
+                issuer: did,
+                vc_types: vc_types.clone(),
+                valid_from: None,
+                valid_until: None,
+                created_at,
+            });
+        Ok(entries.collect())
+    }
+
+    async fn verify_all(
+        &self,
+        ver_model: &mut verification::Model,
+        vp_token: String,
+        presentation_submission: Option<String>,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<Vec<String>> {
         info!("Verifying all");
 
-        let (vcs, holder) = self.verify_vp(ver_model, &vp_token)?;
-        for vc in vcs {
-            self.verify_vc(&vc, &holder)?;
+        let mut presented_ids = Vec::new();
+        match self.config.get_query_language() {
+            QueryLanguage::Pex => {
+                let submission: PresentationSubmission =
+                    match presentation_submission.as_deref().map(serde_json::from_str) {
+                        Some(Ok(data)) => data,
+                        Some(Err(e)) => {
+                            let error = Errors::format_new(
+                                BadFormat::Received,
+                                &format!("presentation_submission is malformed -> {}", e),
+                            );
+                            error!("{}", error.log());
+                            bail!(error);
+                        }
+                        None => {
+                            let error = Errors::format_new(
+                                BadFormat::Received,
+                                "presentation_submission is required",
+                            );
+                            error!("{}", error.log());
+                            bail!(error);
+                        }
+                    };
+                let (vp, verified) = match SdJwt::is_sd_jwt(&vp_token) {
+                    // SD-JWT VCs are presented on their own, the descriptor path "$" selects the whole token
+                    true => {
+                        let (_, claims) = self.verify_sd_jwt(ver_model, &vp_token, trusted).await?;
+                        (
+                            Value::String(vp_token.clone()),
+                            HashMap::from([(vp_token.clone(), claims)]),
+                        )
+                    }
+                    false => {
+                        let (vp, holder) = self.verify_vp(ver_model, &vp_token).await?;
+                        let mut verified = HashMap::new();
+                        for vc in self.retrieve_vcs(&vp)? {
+                            let claims = self.verify_vc(&vc, &holder, trusted).await?;
+                            verified.insert(vc, claims);
+                        }
+                        (vp, verified)
+                    }
+                };
+                presented_ids.extend(verified.values().filter_map(credential_id));
+                let vpd = self.generate_vpd(ver_model.clone())?;
+                self.validate_submission(&vpd, &submission, &vp, &verified)?;
+            }
+            QueryLanguage::Dcql => {
+                // DCQL responses map every credential query id to the presentations answering it
+                let response: HashMap<String, Value> = match serde_json::from_str(&vp_token) {
+                    Ok(data) => data,
+                    Err(e) => {
+                        let error = Errors::format_new(
+                            BadFormat::Received,
+                            &format!("vp_token is not a DCQL response -> {}", e),
+                        );
+                        error!("{}", error.log());
+                        bail!(error);
+                    }
+                };
+                let mut presented: HashMap<String, Vec<(String, Value)>> = HashMap::new();
+                for (id, presentations) in response {
+                    let presentations = match presentations {
+                        Value::Array(items) => items,
+                        item => vec![item],
+                    };
+                    for presentation in presentations {
+                        let presentation = match presentation.as_str() {
+                            Some(data) => data.to_string(),
+                            None => {
+                                let error = Errors::format_new(
+                                    BadFormat::Received,
+                                    &format!("Presentation for {} is not a JWT", id),
+                                );
+                                error!("{}", error.log());
+                                bail!(error);
+                            }
+                        };
+                        if SdJwt::is_sd_jwt(&presentation) {
+                            let credential = self
+                                .verify_sd_jwt(ver_model, &presentation, trusted)
+                                .await?;
+                            presented_ids.extend(credential_id(&credential.1));
+                            presented.entry(id.clone()).or_default().push(credential);
+                            continue;
+                        }
+                        let (vp, holder) = self.verify_vp(ver_model, &presentation).await?;
+                        for vc in self.retrieve_vcs(&vp)? {
+                            let claims = self.verify_vc(&vc, &holder, trusted).await?;
+                            presented_ids.extend(credential_id(&claims));
+                            presented
+                                .entry(id.clone())
+                                .or_default()
+                                .push(("jwt_vc_json".to_string(), vc_claims(&claims).clone()));
+                        }
+                    }
+                }
+                ver_model.vpt = Some(vp_token);
+                let query = self.generate_dcql(ver_model)?;
+                self.validate_dcql(&query, &presented)?;
+            }
         }
         info!("VP & VC Validated successfully");
 
-        Ok(())
+        Ok(presented_ids)
     }
 
-    fn verify_vp(&self, model: &mut verification::Model, vp_token: &str) -> anyhow::Result<(Vec<String>, String)> {
+    async fn verify_vp(
+        &self,
+        model: &mut verification::Model,
+        vp_token: &str,
+    ) -> anyhow::Result<(Value, String)> {
         info!("Verifying vp");
 
         model.vpt = Some(vp_token.to_string());
-        let (token, kid) = self.validate_token(vp_token, Some(&model.state))?;
+        let audience = self.expected_audience(model).await?;
+        let (token, kid) = self.validate_token(vp_token, Some(&audience)).await?;
+        self.validate_iat(&token, Some(model))?;
         self.validate_nonce(model, &token)?;
+        self.validate_jti(&token)?;
         self.validate_vp_subject(model, &token, &kid)?;
         self.validate_vp_id(model, &token)?;
         self.validate_holder(model, &token)?;
-        // let id = match token.claims["jti"].as_str() {
-        //     Some(data) => data,
-        //     None => {
-        //         let error = CommonErrors::format_new(
-        //             BadFormat::Received,
-        //             Some("VPT does not contain the 'jti' field".to_string()),
-        //         );
-        //         error!("{}", error.log());
-        //         bail!(error);
-        //     }
-        // };
 
         info!("VP Verification successful");
-        let vcs = self.retrieve_vcs(token)?;
-
-        Ok((vcs, kid))
+        Ok((token.claims, kid))
     }
 
-    fn verify_vc(&self, vc_token: &str, holder: &str) -> anyhow::Result<()> {
+    async fn verify_vc(
+        &self,
+        vc_token: &str,
+        holder: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<Value> {
         info!("Verifying vc");
 
-        let (token, kid) = self.validate_token(vc_token, None)?;
+        let (token, kid) = self.validate_token(vc_token, None).await?;
+        self.validate_iat(&token, None)?;
         self.validate_issuer(&token, &kid)?;
         self.validate_vc_id(&token)?;
         self.validate_vc_sub(&token, holder)?;
-
-        // if issuers_list.contains(kid) {
-        //     // TODO
-        //     error!("VCT issuer is not on the trusted issuers list");
-        //     bail!("VCT issuer is not on the trusted issuers list");
-        // }
-        // info!("VCT issuer is on the trusted issuers list");
+        self.validate_trusted_issuer(&token, &kid, trusted)?;
 
         self.validate_valid_from(&token)?;
         self.validate_valid_until(&token)?;
+        self.validate_status(&token, &kid).await?;
 
         info!("VC Verification successful");
 
+        Ok(token.claims)
+    }
+
+    async fn verify_sd_jwt(
+        &self,
+        model: &mut verification::Model,
+        presentation: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<(String, Value)> {
+        info!("Verifying SD-JWT VC");
+
+        model.vpt = Some(presentation.to_string());
+        let sd_jwt = SdJwt::parse(presentation)?;
+        let (token, kid) = self.validate_token(&sd_jwt.issuer_jwt, None).await?;
+        let format = match token.header.typ.as_deref() {
+            Some("vc+sd-jwt") => "vc+sd-jwt",
+            _ => "dc+sd-jwt",
+        };
+        self.validate_iat(&token, None)?;
+        self.validate_trusted_issuer(&token, &kid, trusted)?;
+        self.validate_status(&token, &kid).await?;
+        let claims = sd_jwt.disclose(&token.claims)?;
+        self.validate_key_binding(model, &sd_jwt, &token).await?;
+
+        info!("SD-JWT VC Verification successful");
+        Ok((format.to_string(), claims))
+    }
+
+    fn validate_submission(
+        &self,
+        vpd: &VPDef,
+        submission: &PresentationSubmission,
+        vp: &Value,
+        verified: &HashMap<String, Value>,
+    ) -> anyhow::Result<()> {
+        info!("Validating presentation submission");
+
+        if submission.definition_id != vpd.id {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                &format!(
+                    "Submission answers definition {} instead of {}",
+                    submission.definition_id, vpd.id
+                ),
+            );
+            error!("{}", error.log());
+            bail!(error);
+        }
+        if let Some(entry) = submission.descriptor_map.iter().find(|entry| {
+            vpd.input_descriptors
+                .iter()
+                .all(|descriptor| descriptor.id != entry.id)
+        }) {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                &format!("Submission maps unknown input descriptor {}", entry.id),
+            );
+            error!("{}", error.log());
+            bail!(error);
+        }
+
+        let mut failures: HashMap<String, Vec<String>> = HashMap::new();
+        for descriptor in &vpd.input_descriptors {
+            let entries: Vec<&DescriptorMap> = submission
+                .descriptor_map
+                .iter()
+                .filter(|entry| entry.id == descriptor.id)
+                .collect();
+            let mut reasons = Vec::new();
+            if entries.is_empty() {
+                reasons.push("no credential submitted".to_string());
+            }
+            for entry in entries {
+                match self.resolve_descriptor(entry, vp, verified) {
+                    Ok((format, claims)) => {
+                        reasons.extend(self.check_descriptor(descriptor, &format, claims))
+                    }
+                    Err(reason) => reasons.push(reason),
+                }
+            }
+            if !reasons.is_empty() {
+                failures.insert(descriptor.id.clone(), reasons);
+            }
+        }
+
+        let unmet: Vec<String> = match &vpd.submission_requirements {
+            None => failures
+                .keys()
+                .map(|id| format!("input descriptor {}", id))
+                .collect(),
+            Some(requirements) => requirements
+                .iter()
+                .filter(|requirement| !self.meets_requirement(requirement, vpd, &failures))
+                .map(|requirement| format!("requirement {}", requirement.label()))
+                .collect(),
+        };
+        if !unmet.is_empty() {
+            let report: Vec<String> = failures
+                .iter()
+                .map(|(id, reasons)| format!("{}: {}", id, reasons.join(", ")))
+                .collect();
+            let error = Errors::security_new(&format!(
+                "Presentation submission rejected, unmet {} -> {}",
+                unmet.join(", "),
+                report.join("; ")
+            ));
+            error!("{}", error.log());
+            bail!(error);
+        }
+        info!("Presentation submission satisfies the definition");
+        Ok(())
+    }
+
+    fn validate_dcql(
+        &self,
+        query: &DcqlQuery,
+        presented: &HashMap<String, Vec<(String, Value)>>,
+    ) -> anyhow::Result<()> {
+        info!("Validating presentations against DCQL query");
+
+        for (id, credentials) in presented {
+            let credential_query = match query.get(id) {
+                Some(data) => data,
+                None => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        &format!("No credential query with id {}", id),
+                    );
+                    error!("{}", error.log());
+                    bail!(error);
+                }
+            };
+            if credentials.len() > 1 && !credential_query.multiple {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("Credential query {} accepts a single credential", id),
+                );
+                error!("{}", error.log());
+                bail!(error);
+            }
+            if let Some((format, credential)) = credentials
+                .iter()
+                .find(|(format, credential)| !credential_query.matches(format, credential))
+            {
+                let error = Errors::security_new(&format!(
+                    "{} credential {} does not satisfy query {}",
+                    format,
+                    credential
+                        .get("id")
+                        .and_then(Value::as_str)
+                        .unwrap_or("unknown"),
+                    id
+                ));
+                error!("{}", error.log());
+                bail!(error);
+            }
+        }
+
+        let answered: HashSet<String> = presented.keys().cloned().collect();
+        if !query.is_satisfied_by(&answered) {
+            let error = Errors::security_new(
+                "Presented credentials do not satisfy the required credential sets",
+            );
+            error!("{}", error.log());
+            bail!(error);
+        }
+        info!("DCQL query satisfied");
         Ok(())
     }
 
-    fn validate_token(&self, vp_token: &str, audience: Option<&str>) -> anyhow::Result<(TokenData<Value>, String)> {
+    async fn validate_token(
+        &self,
+        vp_token: &str,
+        audience: Option<&str>,
+    ) -> anyhow::Result<(TokenData<Value>, String)> {
         info!("Validating token");
         let header = jsonwebtoken::decode_header(&vp_token)?;
         let kid_str = match header.kid.as_ref() {
@@ -176,35 +981,19 @@ impl VerifierTrait for BasicVerifierService {
                 bail!(error);
             }
         };
-        // let (kid, kid_id) = split_did(kid_str.as_str()); // TODO KID_ID
-        let (kid, _) = split_did(kid_str.as_str()); // TODO KID_ID
+        let (kid, _) = split_did(kid_str.as_str());
         let alg = header.alg;
 
-        let vec = URL_SAFE_NO_PAD.decode(&(kid.replace("did:jwk:", "")))?;
-        let jwk: Jwk = serde_json::from_slice(&vec)?;
+        let jwk = self.resolver.resolve_key(kid_str).await?;
 
         let key = jsonwebtoken::DecodingKey::from_jwk(&jwk)?;
 
-        let mut val = Validation::new(alg);
-
-        val.required_spec_claims = HashSet::new();
-        val.validate_exp = false;
-        val.validate_nbf = true;
+        let mut val = self.validation(alg);
 
         match audience {
             Some(data) => {
-                let audience = format!(
-                    "{}{}/verifier/verify/{}",
-                    self.config.get_host(),
-                    self.config.get_api_path(),
-                    data
-                );
-                let audience = match self.config.is_local() {
-                    true => audience.replace("127.0.0.1", "host.docker.internal"),
-                    false => audience,
-                };
                 val.validate_aud = true;
-                val.set_audience(&[&(audience)]);
+                val.set_audience(&[data]);
             }
             None => {
                 val.validate_aud = false;
@@ -214,7 +1003,11 @@ impl VerifierTrait for BasicVerifierService {
         let token = match jsonwebtoken::decode::<Value>(&vp_token, &key, &val) {
             Ok(token) => token,
             Err(e) => {
-                let error = Errors::security_new(&format!("VPT signature is incorrect -> {}", e.to_string()));
+                let error = match e.kind() {
+                    ErrorKind::ExpiredSignature => Errors::security_new("Token has expired"),
+                    ErrorKind::ImmatureSignature => Errors::security_new("Token is not valid yet"),
+                    _ => Errors::security_new(&format!("VPT signature is incorrect -> {}", e)),
+                };
                 error!("{}", error.log());
                 bail!(error);
             }
@@ -224,7 +1017,80 @@ impl VerifierTrait for BasicVerifierService {
         Ok((token, kid.to_string()))
     }
 
-    fn validate_nonce(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()> {
+    async fn validate_key_binding(
+        &self,
+        model: &mut verification::Model,
+        sd_jwt: &SdJwt,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()> {
+        info!("Validating key binding");
+
+        let kb_jwt = match &sd_jwt.kb_jwt {
+            Some(data) => data,
+            None => {
+                let error = Errors::security_new("SD-JWT presentation has no key binding JWT");
+                error!("{}", error.log());
+                bail!(error);
+            }
+        };
+        let header = jsonwebtoken::decode_header(kb_jwt)?;
+        if header.typ.as_deref() != Some("kb+jwt") {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                "Key binding JWT must have typ 'kb+jwt'",
+            );
+            error!("{}", error.log());
+            bail!(error);
+        }
+        // The holder key is either embedded in cnf or referenced by a DID URL
+        let cnf = token.claims.get("cnf");
+        let holder_kid = cnf.and_then(|cnf| cnf.get("kid")).and_then(Value::as_str);
+        let jwk: Jwk = match (cnf.and_then(|cnf| cnf.get("jwk")), holder_kid) {
+            (Some(jwk), _) => serde_json::from_value(jwk.clone())?,
+            (None, Some(kid)) => self.resolver.resolve_key(kid).await?,
+            (None, None) => {
+                let error = Errors::security_new("SD-JWT VC is not bound to a holder key");
+                error!("{}", error.log());
+                bail!(error);
+            }
+        };
+
+        let key = DecodingKey::from_jwk(&jwk)?;
+        let mut val = self.validation(header.alg);
+        val.validate_aud = true;
+        val.set_audience(&[self.expected_audience(model).await?]);
+        let kb_token = match jsonwebtoken::decode::<Value>(kb_jwt, &key, &val) {
+            Ok(data) => data,
+            Err(e) => {
+                let error = Errors::security_new(&format!("Key binding JWT is incorrect -> {}", e));
+                error!("{}", error.log());
+                bail!(error);
+            }
+        };
+        self.validate_iat(&kb_token, Some(model))?;
+        self.validate_nonce(model, &kb_token)?;
+        if get_claim(&kb_token.claims, vec!["sd_hash"])? != sd_jwt.sd_hash() {
+            let error = Errors::security_new("Key binding sd_hash does not match the presentation");
+            error!("{}", error.log());
+            bail!(error);
+        }
+
+        let holder = match holder_kid {
+            Some(kid) => Some(split_did(kid).0.to_string()),
+            None => get_opt_claim(&token.claims, vec!["sub"])?,
+        };
+        if holder.is_some() {
+            model.holder = holder;
+        }
+        info!("Key binding is correct");
+        Ok(())
+    }
+
+    fn validate_nonce(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()> {
         info!("Validating nonce");
 
         let nonce = get_claim(&token.claims, vec!["nonce"])?;
@@ -280,12 +1146,19 @@ impl VerifierTrait for BasicVerifierService {
         info!("Validating VC subject");
 
         let sub = get_opt_claim(&token.claims, vec!["sub"])?;
-        let cred_sub_id = get_claim(&token.claims, vec!["vc", "CredentialSubject", "id"])?;
+        let vc = vc_claims(&token.claims);
+        // Credentials issued before the data model split used "CredentialSubject"
+        let cred_sub_id = match get_opt_claim(vc, vec!["credentialSubject", "id"])? {
+            Some(data) => data,
+            None => get_claim(vc, vec!["CredentialSubject", "id"])?,
+        };
 
         match sub {
             Some(sub) => {
                 if sub != holder {
-                    let error = Errors::security_new("VCT token sub, credential subject & VP Holder do not match");
+                    let error = Errors::security_new(
+                        "VCT token sub, credential subject & VP Holder do not match",
+                    );
                     error!("{}", error.log());
                     bail!(error);
                 }
@@ -295,7 +1168,8 @@ impl VerifierTrait for BasicVerifierService {
         }
 
         if holder != cred_sub_id {
-            let error = Errors::security_new("VCT token sub, credential subject & VP Holder do not match");
+            let error =
+                Errors::security_new("VCT token sub, credential subject & VP Holder do not match");
             error!("{}", error.log());
             bail!(error);
         }
@@ -303,7 +1177,11 @@ impl VerifierTrait for BasicVerifierService {
         Ok(())
     }
 
-    fn validate_vp_id(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()> {
+    fn validate_vp_id(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()> {
         info!("Validating vp id");
 
         let vp_id = get_claim(&token.claims, vec!["vp", "id"])?;
@@ -318,7 +1196,11 @@ impl VerifierTrait for BasicVerifierService {
         Ok(())
     }
 
-    fn validate_holder(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()> {
+    fn validate_holder(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()> {
         info!("Validating holder");
 
         let vp_holder = get_claim(&token.claims, vec!["vp", "holder"])?;
@@ -337,7 +1219,11 @@ impl VerifierTrait for BasicVerifierService {
         info!("Validating issuer");
 
         let iss = get_opt_claim(&token.claims, vec!["iss"])?;
-        let vc_iss_id = get_claim(&token.claims, vec!["vc", "issuer", "id"])?;
+        let vc = vc_claims(&token.claims);
+        let vc_iss_id = match vc.get("issuer") {
+            Some(Value::String(_)) => get_claim(vc, vec!["issuer"])?,
+            _ => get_claim(vc, vec!["issuer", "id"])?,
+        };
 
         match iss {
             Some(iss) => {
@@ -362,10 +1248,70 @@ impl VerifierTrait for BasicVerifierService {
         Ok(())
     }
 
+    fn validate_trusted_issuer(
+        &self,
+        token: &TokenData<Value>,
+        kid: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<()> {
+        info!("Validating trusted issuer");
+
+        let entries: Vec<&trusted_issuer::Model> =
+            trusted.iter().filter(|entry| entry.issuer == kid).collect();
+        if entries.is_empty() {
+            let error = Errors::security_new(&format!(
+                "VCT issuer {} is not on the trusted issuers list",
+                kid
+            ));
+            error!("{}", error.log());
+            bail!(error);
+        }
+
+        let vc_types: Vec<&str> = match vc_claims(&token.claims)
+            .get("type")
+            .or(token.claims.get("vct"))
+        {
+            Some(Value::Array(types)) => types.iter().filter_map(|t| t.as_str()).collect(),
+            Some(Value::String(vc_type)) => vec![vc_type.as_str()],
+            _ => vec![],
+        };
+        let now = Utc::now().naive_utc();
+        let in_scope = entries.iter().any(|entry| {
+            entry.valid_from.is_none_or(|from| from <= now)
+                && entry.valid_until.is_none_or(|until| until > now)
+                && entry
+                    .vc_types
+                    .iter()
+                    .any(|vc_type| vc_types.contains(&vc_type.as_str()))
+        });
+        if !in_scope {
+            let error = Errors::security_new(&format!(
+                "VCT issuer {} is not trusted to issue {:?} at this time",
+                kid, vc_types
+            ));
+            error!("{}", error.log());
+            bail!(error);
+        }
+        info!("VCT issuer is on the trusted issuers list");
+        Ok(())
+    }
+
+    async fn validate_status(&self, token: &TokenData<Value>, kid: &str) -> anyhow::Result<()> {
+        info!("Validating credential status");
+
+        match vc_claims(&token.claims).get("credentialStatus") {
+            Some(credential_status) => self.status.check_status(credential_status, kid).await,
+            None => {
+                info!("VC carries no credential status");
+                Ok(())
+            }
+        }
+    }
+
     fn validate_vc_id(&self, token: &TokenData<Value>) -> anyhow::Result<()> {
         info!("Validating VC id & JTI");
 
-        let vc_id = get_claim(&token.claims, vec!["vc", "id"])?;
+        let vc_id = get_claim(vc_claims(&token.claims), vec!["id"])?;
         let jti = get_opt_claim(&token.claims, vec!["jti"])?;
 
         match jti {
@@ -387,7 +1333,11 @@ impl VerifierTrait for BasicVerifierService {
     fn validate_valid_from(&self, token: &TokenData<Value>) -> anyhow::Result<()> {
         info!("Validating issuance date");
 
-        let valid_from = get_opt_claim(&token.claims, vec!["vc", "validFrom"])?;
+        let vc = vc_claims(&token.claims);
+        let valid_from = match get_opt_claim(vc, vec!["validFrom"])? {
+            Some(data) => Some(data),
+            None => get_opt_claim(vc, vec!["issuanceDate"])?,
+        };
 
         match valid_from {
             Some(valid_from) => {
@@ -400,7 +1350,10 @@ impl VerifierTrait for BasicVerifierService {
                         }
                     }
                     Err(e) => {
-                        let error = Errors::security_new(&format!("VC iat and issuanceDate do not match -> {}", e));
+                        let error = Errors::security_new(&format!(
+                            "VC iat and issuanceDate do not match -> {}",
+                            e
+                        ));
                         error!("{}", error.log());
                         bail!(error);
                     }
@@ -416,7 +1369,11 @@ impl VerifierTrait for BasicVerifierService {
     fn validate_valid_until(&self, token: &TokenData<Value>) -> anyhow::Result<()> {
         info!("Validating expiration date");
 
-        let valid_until = get_opt_claim(&token.claims, vec!["vc", "validUntil"])?;
+        let vc = vc_claims(&token.claims);
+        let valid_until = match get_opt_claim(vc, vec!["validUntil"])? {
+            Some(data) => Some(data),
+            None => get_opt_claim(vc, vec!["expirationDate"])?,
+        };
         // let valid_until = get_claim(&token.claims, vec!["vc", "validUntil"])?;
 
         match valid_until {
@@ -430,7 +1387,10 @@ impl VerifierTrait for BasicVerifierService {
                         }
                     }
                     Err(e) => {
-                        let error = Errors::security_new(&format!("VC validUntil has invalid format -> {}", e));
+                        let error = Errors::security_new(&format!(
+                            "VC validUntil has invalid format -> {}",
+                            e
+                        ));
                         error!("{}", error.log());
                         bail!(error);
                     }
@@ -443,22 +1403,23 @@ impl VerifierTrait for BasicVerifierService {
         Ok(())
     }
 
-    fn retrieve_vcs(&self, token: TokenData<Value>) -> anyhow::Result<Vec<String>> {
+    fn retrieve_vcs(&self, vp: &Value) -> anyhow::Result<Vec<String>> {
         info!("Retrieving VCs");
-        let vcs: Vec<String> = match serde_json::from_value(token.claims["vp"]["verifiableCredential"].clone()) {
-            Ok(data) => data,
-            Err(e) => {
-                let error = Errors::format_new(
-                    BadFormat::Received,
-                    &format!(
-                        "VPT does not contain the 'verifiableCredential' field -> {}",
-                        e.to_string()
-                    ),
-                );
-                error!("{}", error.log());
-                bail!(error);
-            }
-        };
+        let vcs: Vec<String> =
+            match serde_json::from_value(vp["vp"]["verifiableCredential"].clone()) {
+                Ok(data) => data,
+                Err(e) => {
+                    let error = Errors::format_new(
+                        BadFormat::Received,
+                        &format!(
+                            "VPT does not contain the 'verifiableCredential' field -> {}",
+                            e
+                        ),
+                    );
+                    error!("{}", error.log());
+                    bail!(error);
+                }
+            };
         Ok(vcs)
     }
 }
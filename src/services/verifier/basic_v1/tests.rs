@@ -0,0 +1,224 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::BasicVerifierService;
+use crate::config::CoreApplicationConfig;
+use crate::data::entities::verification;
+use crate::errors::Errors;
+use crate::services::client::basic::BasicClientService;
+use crate::services::resolver::JwkDidResolver;
+use crate::services::signer::memory::config::MemorySignerConfig;
+use crate::services::signer::memory::MemorySignerService;
+use crate::services::signer::KeyMaterial;
+use crate::services::status_checker::cached::{
+    config::CachedStatusCheckerConfig, CachedStatusCheckerService,
+};
+use crate::services::verifier::VerifierTrait;
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::client_id_scheme::ClientIdScheme;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::types::vcs::SdJwt;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::Utc;
+use jsonwebtoken::{Header, TokenData};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use urlencoding::encode;
+
+// Disclosure and digest from the SD-JWT specification examples
+const DISCLOSURE: &str = "WyI2cU1RdlJMNWhhaiIsICJmYW1pbHlfbmFtZSIsICJNw7ZiaXVzIl0";
+const DIGEST: &str = "uutlBuYeMDyjLLTpf6Jxi7yNkEF35jdyWMn9U7b_RYY";
+
+fn verifier() -> BasicVerifierService {
+    let config = CoreApplicationConfig {
+        signing_alg: SigningAlgorithm::ES256,
+        vp_client_id_scheme: ClientIdScheme::Did,
+        credential_registry: Arc::new(
+            CredentialRegistry::load("static/credentials/registry.json").unwrap(),
+        ),
+        ..Default::default()
+    };
+    let resolver = Arc::new(JwkDidResolver::new());
+    let signer = MemorySignerService::new(MemorySignerConfig::from(config.clone())).unwrap();
+    let status = CachedStatusCheckerService::new(
+        CachedStatusCheckerConfig::from(config.clone()),
+        Arc::new(BasicClientService::new()),
+        resolver.clone(),
+    );
+    BasicVerifierService::new(config.into(), resolver, Arc::new(signer), Arc::new(status))
+}
+
+fn model() -> verification::Model {
+    verification::Model {
+        id: "id".to_string(),
+        state: "state".to_string(),
+        nonce: "nonce".to_string(),
+        vc_type: "DataspaceParticipantCredential".to_string(),
+        audience: "https://verifier.example.com/verify".to_string(),
+        response_key: None,
+        holder: None,
+        vpt: None,
+        success: None,
+        reason: None,
+        status: "Pending".to_string(),
+        created_at: Utc::now().naive_utc(),
+        ended_at: None,
+    }
+}
+
+// Issuer JWT claims binding the credential to the holder key
+fn bound_to(holder: &KeyMaterial) -> TokenData<Value> {
+    TokenData {
+        header: Header::default(),
+        claims: json!({ "_sd": [DIGEST], "cnf": { "jwk": holder.get_jwk() } }),
+    }
+}
+
+fn presentation(kb_jwt: Option<String>) -> SdJwt {
+    SdJwt {
+        issuer_jwt: "issuer-jwt".to_string(),
+        disclosures: vec![DISCLOSURE.to_string()],
+        kb_jwt,
+    }
+}
+
+fn kb_jwt(holder: &KeyMaterial, aud: &str, iat: i64, sd_hash: &str) -> String {
+    let header = Header {
+        typ: Some("kb+jwt".to_string()),
+        ..Default::default()
+    };
+    let claims = json!({
+        "iat": iat,
+        "aud": aud,
+        "nonce": model().nonce,
+        "sd_hash": sd_hash,
+    });
+    holder.sign(header, &claims).unwrap()
+}
+
+fn security_cause(result: anyhow::Result<impl std::fmt::Debug>) -> String {
+    match result.unwrap_err().downcast::<Errors>().unwrap() {
+        Errors::SecurityError { cause, .. } => cause,
+        other => panic!("expected a security error, got {:?}", other),
+    }
+}
+
+#[test]
+fn referenced_disclosure_is_put_in_place_of_its_digest() {
+    let claims = presentation(None)
+        .disclose(&json!({ "_sd": [DIGEST], "_sd_alg": "sha-256" }))
+        .unwrap();
+    assert_eq!(claims, json!({ "family_name": "Möbius" }));
+}
+
+#[test]
+fn disclosure_without_a_matching_digest_is_rejected() {
+    let forged = json!({ "_sd": ["X9yH0Ajrdm1Oij4tWso9UzzKJvPoDxwmuEcO3XAdRC0"] });
+    let cause = security_cause(presentation(None).disclose(&forged));
+    assert!(cause.contains("not referenced"));
+}
+
+#[tokio::test]
+async fn key_binding_matching_the_presentation_is_accepted() {
+    let verifier = verifier();
+    let holder = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let mut model = model();
+    let aud = verifier.get_client_id(&model).await.unwrap();
+
+    let mut sd_jwt = presentation(None);
+    sd_jwt.kb_jwt = Some(kb_jwt(
+        &holder,
+        &aud,
+        Utc::now().timestamp(),
+        &sd_jwt.sd_hash(),
+    ));
+    verifier
+        .validate_key_binding(&mut model, &sd_jwt, &bound_to(&holder))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn presentation_without_key_binding_is_rejected() {
+    let verifier = verifier();
+    let holder = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+
+    let result = verifier
+        .validate_key_binding(&mut model(), &presentation(None), &bound_to(&holder))
+        .await;
+    assert!(security_cause(result).contains("no key binding JWT"));
+}
+
+#[tokio::test]
+async fn key_binding_issued_before_the_session_is_rejected() {
+    let verifier = verifier();
+    let holder = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let mut model = model();
+    let aud = verifier.get_client_id(&model).await.unwrap();
+
+    let mut sd_jwt = presentation(None);
+    let stale = Utc::now().timestamp() - 3600;
+    sd_jwt.kb_jwt = Some(kb_jwt(&holder, &aud, stale, &sd_jwt.sd_hash()));
+    let result = verifier
+        .validate_key_binding(&mut model, &sd_jwt, &bound_to(&holder))
+        .await;
+    assert!(security_cause(result).contains("before the verification started"));
+}
+
+#[tokio::test]
+async fn key_binding_over_other_disclosures_is_rejected() {
+    let verifier = verifier();
+    let holder = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let mut model = model();
+    let aud = verifier.get_client_id(&model).await.unwrap();
+
+    // The holder signed a presentation that withheld the disclosure
+    let withheld = SdJwt {
+        disclosures: vec![],
+        ..presentation(None)
+    };
+    let sd_jwt = presentation(Some(kb_jwt(
+        &holder,
+        &aud,
+        Utc::now().timestamp(),
+        &withheld.sd_hash(),
+    )));
+    let result = verifier
+        .validate_key_binding(&mut model, &sd_jwt, &bound_to(&holder))
+        .await;
+    assert!(security_cause(result).contains("sd_hash does not match"));
+}
+
+#[tokio::test]
+async fn did_client_id_carries_its_scheme_as_a_prefix() {
+    let verifier = verifier();
+    let client_id = verifier.get_client_id(&model()).await.unwrap();
+    assert!(client_id.starts_with("decentralized_identifier:did:jwk:"));
+
+    let uri = verifier.generate_verification_uri(model()).await.unwrap();
+    assert!(uri.contains(&format!("client_id={}&", encode(&client_id))));
+    assert!(!uri.contains("client_id_scheme"));
+
+    let request = verifier.generate_request_object(model()).await.unwrap();
+    let payload = request.split('.').nth(1).unwrap();
+    let claims: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+    assert_eq!(claims["client_id"], json!(client_id));
+    assert!(claims.get("client_id_scheme").is_none());
+}
@@ -17,28 +17,111 @@
  *
  */
 
-use crate::data::entities::verification;
-use crate::types::enums::vc_type::VcType;
-use crate::types::vcs::VPDef;
+use crate::data::entities::{trusted_issuer, verification};
+use crate::types::dcql::DcqlQuery;
+use crate::types::vcs::{PresentationSubmission, SdJwt, VPDef};
+use crate::types::verifying::VerifyPayload;
+use async_trait::async_trait;
 use jsonwebtoken::TokenData;
 use serde_json::Value;
+use std::collections::HashMap;
 
+#[async_trait]
 pub trait VerifierTrait: Send + Sync + 'static {
-    fn start_vp(&self, id: &str, vc_type: VcType) -> anyhow::Result<verification::NewModel>;
-    fn generate_verification_uri(&self, model: verification::Model) -> String;
-    fn generate_vpd(&self, ver_model: verification::Model) -> VPDef;
-    fn verify_all(&self, ver_model: &mut verification::Model, vp_token: String) -> anyhow::Result<()>;
-    fn verify_vp(&self, model: &mut verification::Model, vp_token: &str) -> anyhow::Result<(Vec<String>, String)>;
-    fn verify_vc(&self, vc_token: &str, holder: &str) -> anyhow::Result<()>;
-    fn validate_token(&self, vp_token: &str, audience: Option<&str>) -> anyhow::Result<(TokenData<Value>, String)>;
-    fn validate_nonce(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()>;
-    fn validate_vp_subject(&self, model: &mut verification::Model, token: &TokenData<Value>, kid: &str) -> anyhow::Result<()>;
+    fn start_vp(&self, id: &str, vc_type: &str) -> anyhow::Result<verification::NewModel>;
+    fn is_expired(&self, model: &verification::Model) -> bool;
+    fn claim_state(&self, model: &verification::Model) -> anyhow::Result<()>;
+    async fn get_client_id(&self, model: &verification::Model) -> anyhow::Result<String>;
+    async fn generate_verification_uri(&self, model: verification::Model)
+        -> anyhow::Result<String>;
+    async fn generate_request_object(&self, model: verification::Model) -> anyhow::Result<String>;
+    fn read_response(
+        &self,
+        model: &verification::Model,
+        payload: VerifyPayload,
+    ) -> anyhow::Result<(String, Option<String>)>;
+    fn generate_vpd(&self, ver_model: verification::Model) -> anyhow::Result<VPDef>;
+    fn generate_dcql(&self, ver_model: &verification::Model) -> anyhow::Result<DcqlQuery>;
+    async fn own_trusted_issuers(&self) -> anyhow::Result<Vec<trusted_issuer::Model>>;
+    async fn verify_all(
+        &self,
+        ver_model: &mut verification::Model,
+        vp_token: String,
+        presentation_submission: Option<String>,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<Vec<String>>;
+    async fn verify_vp(
+        &self,
+        model: &mut verification::Model,
+        vp_token: &str,
+    ) -> anyhow::Result<(Value, String)>;
+    async fn verify_vc(
+        &self,
+        vc_token: &str,
+        holder: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<Value>;
+    async fn verify_sd_jwt(
+        &self,
+        model: &mut verification::Model,
+        presentation: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<(String, Value)>;
+    fn validate_submission(
+        &self,
+        vpd: &VPDef,
+        submission: &PresentationSubmission,
+        vp: &Value,
+        verified: &HashMap<String, Value>,
+    ) -> anyhow::Result<()>;
+    fn validate_dcql(
+        &self,
+        query: &DcqlQuery,
+        presented: &HashMap<String, Vec<(String, Value)>>,
+    ) -> anyhow::Result<()>;
+    async fn validate_token(
+        &self,
+        vp_token: &str,
+        audience: Option<&str>,
+    ) -> anyhow::Result<(TokenData<Value>, String)>;
+    async fn validate_key_binding(
+        &self,
+        model: &mut verification::Model,
+        sd_jwt: &SdJwt,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()>;
+    fn validate_nonce(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()>;
+    fn validate_vp_subject(
+        &self,
+        model: &mut verification::Model,
+        token: &TokenData<Value>,
+        kid: &str,
+    ) -> anyhow::Result<()>;
     fn validate_vc_sub(&self, token: &TokenData<Value>, holder: &str) -> anyhow::Result<()>;
-    fn validate_vp_id(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()>;
-    fn validate_holder(&self, model: &verification::Model, token: &TokenData<Value>) -> anyhow::Result<()>;
+    fn validate_vp_id(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()>;
+    fn validate_holder(
+        &self,
+        model: &verification::Model,
+        token: &TokenData<Value>,
+    ) -> anyhow::Result<()>;
     fn validate_issuer(&self, token: &TokenData<Value>, kid: &str) -> anyhow::Result<()>;
+    fn validate_trusted_issuer(
+        &self,
+        token: &TokenData<Value>,
+        kid: &str,
+        trusted: &[trusted_issuer::Model],
+    ) -> anyhow::Result<()>;
+    async fn validate_status(&self, token: &TokenData<Value>, kid: &str) -> anyhow::Result<()>;
     fn validate_vc_id(&self, token: &TokenData<Value>) -> anyhow::Result<()>;
     fn validate_valid_from(&self, token: &TokenData<Value>) -> anyhow::Result<()>;
     fn validate_valid_until(&self, token: &TokenData<Value>) -> anyhow::Result<()>;
-    fn retrieve_vcs(&self, token: TokenData<Value>) -> anyhow::Result<Vec<String>>;
+    fn retrieve_vcs(&self, vp: &Value) -> anyhow::Result<Vec<String>>;
 }
@@ -18,7 +18,6 @@
  */
 
 use crate::data::entities::minions::NewModel;
-use crate::types::issuing::WellKnownJwks;
 use crate::types::wallet::{DidsInfo, KeyDefinition, WalletInfo, WalletSession};
 use async_trait::async_trait;
 use serde_json::Value;
@@ -42,7 +41,6 @@ pub trait WalletTrait: Send + Sync + 'static {
     async fn retrieve_wallet_info(&self) -> anyhow::Result<()>;
     async fn retrieve_keys(&self) -> anyhow::Result<()>;
     async fn retrieve_wallet_dids(&self) -> anyhow::Result<()>;
-    fn get_jwks_data(&self) -> anyhow::Result<WellKnownJwks>;
     // REGISTER STUFF IN WALLET
     async fn register_key(&self) -> anyhow::Result<()>;
     async fn register_did(&self) -> anyhow::Result<()>;
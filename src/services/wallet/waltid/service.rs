@@ -24,7 +24,6 @@ use crate::errors::{ErrorLogTrait, Errors};
 use crate::services::client::ClientServiceTrait;
 use crate::types::enums::errors::{BadFormat, MissingAction};
 use crate::types::enums::request::Body;
-use crate::types::issuing::WellKnownJwks;
 use crate::types::jwt::AuthJwtClaims;
 use crate::types::wallet::{
     DidsInfo, KeyDefinition, WalletInfo, WalletInfoResponse, WalletLoginResponse, WalletSession,
@@ -35,8 +34,6 @@ use axum::http::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use axum::http::HeaderMap;
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine;
-use rsa::pkcs8::DecodePrivateKey;
-use rsa::RsaPrivateKey;
 use serde_json::Value;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -498,14 +495,6 @@ impl WalletTrait for WaltIdService {
         Ok(())
     }
 
-    fn get_jwks_data(&self) -> anyhow::Result<WellKnownJwks> {
-        info!("Retrieving jwks data");
-
-        let n_key = self.config.get_priv_key()?;
-        let key = RsaPrivateKey::from_pkcs8_pem(&n_key)?;
-        Ok(WellKnownJwks::new(key))
-    }
-
     // REGISTER STUFF IN WALLET ----------------------------------------------------------------------------->
     async fn register_key(&self) -> anyhow::Result<()> {
         info!("Registering key in web wallet");
@@ -18,44 +18,131 @@
  */
 
 use crate::config::{CoreApplicationConfig, CoreApplicationConfigTrait};
+use crate::core::traits::CoreMinionsTrait;
 use crate::core::Core;
 use crate::http::RainbowAuthorityRouter;
 use crate::services::client::basic::BasicClientService;
 use crate::services::gatekeeper::gnap::{config::GnapConfig, GnapService};
 use crate::services::issuer::basic_v1::{config::BasicIssuerConfig, BasicIssuerService};
+use crate::services::notifier::smtp::{config::SmtpNotifierConfig, SmtpNotifierService};
+use crate::services::notifier::webhook::{config::WebhookNotifierConfig, WebhookNotifierService};
+use crate::services::notifier::NotifierTrait;
 use crate::services::repo::postgres::RepoForSql;
+use crate::services::resolver::cached::{config::CachedResolverConfig, CachedResolverService};
+use crate::services::signer::file::{config::FileSignerConfig, FileSignerService};
+use crate::services::signer::memory::{config::MemorySignerConfig, MemorySignerService};
+use crate::services::signer::waltid::{config::WaltIdSignerConfig, WaltIdSignerService};
+use crate::services::signer::SignerTrait;
+use crate::services::status_checker::cached::{
+    config::CachedStatusCheckerConfig, CachedStatusCheckerService,
+};
 use crate::services::verifier::basic_v1::{config::BasicVerifierConfig, BasicVerifierService};
 use crate::services::wallet::waltid::{config::WaltIdConfig, WaltIdService};
+use crate::types::enums::notifier_type::NotifierType;
+use crate::types::enums::signer_type::SignerType;
 use axum::{serve, Router};
 use sea_orm::Database;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::net::TcpListener;
-use tracing::info;
+use tracing::{info, warn};
 
 pub struct AuthorityApplication;
 
 pub async fn create_authority_router(config: &CoreApplicationConfig) -> Router {
     // CONFIGS
-    let db_connection = Database::connect(config.get_full_db_url()).await.expect("Database can't connect");
+    let db_connection = Database::connect(config.get_full_db_url())
+        .await
+        .expect("Database can't connect");
     let waltid_config = WaltIdConfig::from(config.clone());
     let gnap_config = GnapConfig::from(config.clone());
     let issuer_config = BasicIssuerConfig::from(config.clone());
     let verifier_config = BasicVerifierConfig::from(config.clone());
+    let resolver_config = CachedResolverConfig::from(config.clone());
+    let status_config = CachedStatusCheckerConfig::from(config.clone());
     let core_config = Arc::new(config.clone());
 
     // SERVICES
     let repo = Arc::new(RepoForSql::new(db_connection));
     let client = Arc::new(BasicClientService::new());
     let wallet = Arc::new(WaltIdService::new(waltid_config, client.clone()));
-    let access = Arc::new(GnapService::new(gnap_config, client.clone()));
-    let issuer = Arc::new(BasicIssuerService::new(issuer_config));
-    let verifier = Arc::new(BasicVerifierService::new(verifier_config));
+    let signer: Arc<dyn SignerTrait> = match config.signer_type {
+        SignerType::File => Arc::new(
+            FileSignerService::new(FileSignerConfig::from(config.clone()))
+                .expect("Signing key can't be loaded"),
+        ),
+        SignerType::Memory => Arc::new(
+            MemorySignerService::new(MemorySignerConfig::from(config.clone()))
+                .expect("Signing key can't be generated"),
+        ),
+        SignerType::Wallet => Arc::new(WaltIdSignerService::new(
+            WaltIdSignerConfig::from(config.clone()),
+            wallet.clone(),
+            client.clone(),
+        )),
+    };
+    let access = Arc::new(GnapService::new(
+        gnap_config,
+        client.clone(),
+        signer.clone(),
+    ));
+    let resolver = Arc::new(CachedResolverService::new(resolver_config, client.clone()));
+    let issuer = Arc::new(BasicIssuerService::new(
+        issuer_config,
+        signer.clone(),
+        resolver.clone(),
+    ));
+    let status = Arc::new(CachedStatusCheckerService::new(
+        status_config,
+        client.clone(),
+        resolver.clone(),
+    ));
+    let verifier = Arc::new(BasicVerifierService::new(
+        verifier_config,
+        resolver,
+        signer,
+        status,
+    ));
+    let notifier: Arc<dyn NotifierTrait> = match config.notifier_type {
+        NotifierType::Smtp => Arc::new(
+            SmtpNotifierService::new(SmtpNotifierConfig::from(config.clone()))
+                .expect("SMTP notifier can't be configured"),
+        ),
+        NotifierType::Webhook => Arc::new(WebhookNotifierService::new(
+            WebhookNotifierConfig::from(config.clone()),
+            client.clone(),
+        )),
+    };
 
     // CORE
-    let authority = Core::new(wallet, access, issuer, verifier, repo, client, core_config);
+    let authority = Core::new(
+        wallet,
+        access,
+        issuer,
+        verifier,
+        notifier,
+        repo,
+        client,
+        core_config,
+    );
+
+    let authority = Arc::new(authority);
+    spawn_expiry_reminders(authority.clone(), config.reminder_interval);
 
     // ROUTER
-    RainbowAuthorityRouter::new(Arc::new(authority)).router()
+    RainbowAuthorityRouter::new(authority).router()
+}
+
+fn spawn_expiry_reminders(authority: Arc<Core>, every: u64) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(every));
+        loop {
+            interval.tick().await;
+            if let Err(e) = authority.send_expiry_reminders().await {
+                warn!("Expiry reminders sweep failed: {}", e);
+            }
+        }
+    });
 }
 
 impl AuthorityApplication {
@@ -0,0 +1,139 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use crate::types::enums::vc_data_model::VcDataModel;
+use anyhow::bail;
+use jsonschema::Validator;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::error;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialType {
+    pub name: String,
+    pub format: String,
+    pub claims: Value,
+    pub schema: Option<Value>,
+    #[serde(default)]
+    pub data_model: VcDataModel,
+    pub validity_days: i64,
+    #[serde(default = "default_reminder_days")]
+    pub reminder_days: i64,
+    #[serde(default)]
+    pub display: Vec<CredentialDisplay>,
+    #[serde(skip)]
+    validator: Option<Arc<Validator>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialDisplay {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+}
+
+fn default_reminder_days() -> i64 {
+    30
+}
+
+impl CredentialType {
+    pub fn conf_id(&self) -> String {
+        format!("{}_{}", self.name, self.format)
+    }
+
+    // Placeholders "{{holder}}" & "{{name}}" are replaced in every string of the template
+    pub fn credential_subject(&self, holder: &str, name: &str) -> Value {
+        let mut subject = self.claims.clone();
+        fill_template(&mut subject, holder, name);
+        if let Value::Object(map) = &mut subject {
+            map.insert("id".to_string(), Value::String(holder.to_string()));
+        }
+        subject
+    }
+
+    // The schema is compiled once when the registry is loaded and reused for every subject
+    pub fn compile_schema(&mut self) -> anyhow::Result<()> {
+        if let Some(schema) = &self.schema {
+            self.validator = Some(Arc::new(compile(&self.name, schema)?));
+        }
+        Ok(())
+    }
+
+    pub fn validate_subject(&self, subject: &Value) -> anyhow::Result<()> {
+        let compiled;
+        let validator = match (&self.validator, &self.schema) {
+            (Some(validator), _) => validator.as_ref(),
+            (None, Some(schema)) => {
+                compiled = compile(&self.name, schema)?;
+                &compiled
+            }
+            (None, None) => return Ok(()),
+        };
+        let failures: Vec<String> = validator
+            .iter_errors(subject)
+            .map(|e| format!("{} at '{}'", e, e.instance_path()))
+            .collect();
+        if !failures.is_empty() {
+            let error = Errors::format_new(
+                BadFormat::Sent,
+                &format!(
+                    "Credential subject does not match {} schema: {}",
+                    self.name,
+                    failures.join("; ")
+                ),
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(())
+    }
+}
+
+fn compile(name: &str, schema: &Value) -> anyhow::Result<Validator> {
+    match jsonschema::validator_for(schema) {
+        Ok(data) => Ok(data),
+        Err(e) => {
+            let error = Errors::parse_new(&format!("Invalid schema for {}: {}", name, e));
+            error!("{}", error.log());
+            bail!(error)
+        }
+    }
+}
+
+fn fill_template(value: &mut Value, holder: &str, name: &str) {
+    match value {
+        Value::String(s) => *s = s.replace("{{holder}}", holder).replace("{{name}}", name),
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| fill_template(item, holder, name)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| fill_template(item, holder, name)),
+        _ => {}
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::CredentialType;
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::dcql::VerificationPolicy;
+use crate::types::enums::errors::BadFormat;
+use crate::utils::read;
+use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use tracing::error;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CredentialRegistry {
+    pub credentials: Vec<CredentialType>,
+    #[serde(default)]
+    pub policies: Vec<VerificationPolicy>,
+}
+
+impl CredentialRegistry {
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let data = read(path)?;
+        let mut registry: Self = match serde_json::from_str(&data) {
+            Ok(registry) => registry,
+            Err(e) => {
+                let error =
+                    Errors::parse_new(&format!("Invalid credential registry {}: {}", path, e));
+                error!("{}", error.log());
+                bail!(error)
+            }
+        };
+        for cred in registry.credentials.iter_mut() {
+            cred.compile_schema()?;
+        }
+        Ok(registry)
+    }
+
+    pub fn get(&self, name: &str) -> anyhow::Result<&CredentialType> {
+        match self.credentials.iter().find(|cred| cred.name == name) {
+            Some(cred) => Ok(cred),
+            None => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    &format!("Unknown credential type: {}", name),
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        }
+    }
+
+    pub fn policy(&self, name: &str) -> anyhow::Result<VerificationPolicy> {
+        match self.policies.iter().find(|policy| policy.name == name) {
+            Some(policy) => Ok(policy.clone()),
+            None => Ok(VerificationPolicy::from_type(self.get(name)?)),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+
+// Digital Credentials Query Language (OpenID4VP 1.0, section 6)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DcqlQuery {
+    pub credentials: Vec<CredentialQuery>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_sets: Option<Vec<CredentialSetQuery>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialQuery {
+    pub id: String,
+    pub format: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multiple: bool,
+    pub meta: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claims: Option<Vec<ClaimsQuery>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub claim_sets: Option<Vec<Vec<String>>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaimsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub path: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CredentialSetQuery {
+    pub options: Vec<Vec<String>>,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+impl DcqlQuery {
+    pub fn get(&self, id: &str) -> Option<&CredentialQuery> {
+        self.credentials.iter().find(|query| query.id == id)
+    }
+
+    // Without credential sets every credential query has to be answered
+    pub fn is_satisfied_by(&self, answered: &HashSet<String>) -> bool {
+        match &self.credential_sets {
+            None => self
+                .credentials
+                .iter()
+                .all(|query| answered.contains(&query.id)),
+            Some(sets) => sets.iter().filter(|set| set.required).all(|set| {
+                set.options
+                    .iter()
+                    .any(|option| option.iter().all(|id| answered.contains(id)))
+            }),
+        }
+    }
+}
+
+impl CredentialQuery {
+    pub fn matches(&self, format: &str, credential: &Value) -> bool {
+        format == self.format && self.matches_meta(credential) && self.matches_claims(credential)
+    }
+
+    fn matches_meta(&self, credential: &Value) -> bool {
+        if let Some(Value::Array(type_values)) = self.meta.get("type_values") {
+            let types: Vec<&Value> = match credential.get("type") {
+                Some(Value::Array(types)) => types.iter().collect(),
+                Some(vc_type) => vec![vc_type],
+                None => vec![],
+            };
+            return type_values.iter().any(|required| match required {
+                Value::Array(required) => required.iter().all(|t| types.contains(&t)),
+                _ => false,
+            });
+        }
+        if let Some(Value::Array(vct_values)) = self.meta.get("vct_values") {
+            return credential
+                .get("vct")
+                .is_some_and(|vct| vct_values.contains(vct));
+        }
+        true
+    }
+
+    fn matches_claims(&self, credential: &Value) -> bool {
+        let claims = match &self.claims {
+            Some(claims) => claims,
+            None => return true,
+        };
+        match &self.claim_sets {
+            None => claims.iter().all(|claim| claim.matches(credential)),
+            Some(claim_sets) => claim_sets.iter().any(|claim_set| {
+                claim_set.iter().all(|id| {
+                    claims
+                        .iter()
+                        .find(|claim| claim.id.as_deref() == Some(id))
+                        .is_some_and(|claim| claim.matches(credential))
+                })
+            }),
+        }
+    }
+}
+
+impl ClaimsQuery {
+    // Strings select object keys, integers array indexes and null every array element
+    pub fn select<'a>(&self, credential: &'a Value) -> Vec<&'a Value> {
+        self.path.iter().fold(vec![credential], |nodes, component| {
+            nodes
+                .into_iter()
+                .flat_map(|node| match (component, node) {
+                    (Value::String(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                    (Value::Number(index), Value::Array(items)) => index
+                        .as_u64()
+                        .and_then(|index| items.get(index as usize))
+                        .into_iter()
+                        .collect(),
+                    (Value::Null, Value::Array(items)) => items.iter().collect(),
+                    _ => vec![],
+                })
+                .collect()
+        })
+    }
+
+    pub fn matches(&self, credential: &Value) -> bool {
+        let selected = self.select(credential);
+        match &self.values {
+            Some(values) => selected.iter().any(|claim| values.contains(claim)),
+            None => !selected.is_empty(),
+        }
+    }
+}
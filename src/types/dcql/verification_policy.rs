@@ -0,0 +1,155 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::{ClaimsQuery, CredentialQuery, CredentialSetQuery, DcqlQuery};
+use crate::types::credentials::CredentialType;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerificationPolicy {
+    pub name: String,
+    pub credentials: Vec<PolicyCredential>,
+    #[serde(default)]
+    pub credential_sets: Vec<CredentialSetQuery>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyCredential {
+    pub id: String,
+    pub types: Vec<String>,
+    pub formats: Vec<String>,
+    #[serde(default)]
+    pub claims: Vec<ClaimsQuery>,
+    pub claim_sets: Option<Vec<Vec<String>>>,
+}
+
+impl VerificationPolicy {
+    // Credential types without a configured policy are requested as they are issued
+    pub fn from_type(cred_type: &CredentialType) -> Self {
+        VerificationPolicy {
+            name: cred_type.name.clone(),
+            credentials: vec![PolicyCredential {
+                id: cred_type.name.clone(),
+                types: vec![cred_type.name.clone()],
+                formats: vec![cred_type.format.clone()],
+                claims: vec![],
+                claim_sets: None,
+            }],
+            credential_sets: vec![],
+        }
+    }
+
+    // Every accepted format becomes its own credential query, offered as alternatives
+    pub fn to_dcql(&self) -> DcqlQuery {
+        let mut credentials = Vec::new();
+        let mut variants: HashMap<&str, Vec<String>> = HashMap::new();
+        for cred in &self.credentials {
+            for format in &cred.formats {
+                let id = match cred.formats.len() {
+                    1 => cred.id.clone(),
+                    _ => format!("{}_{}", cred.id, query_id_safe(format)),
+                };
+                credentials.push(CredentialQuery {
+                    id: id.clone(),
+                    format: format.clone(),
+                    multiple: false,
+                    meta: format_meta(format, &cred.types),
+                    claims: (!cred.claims.is_empty()).then(|| cred.claims.clone()),
+                    claim_sets: cred.claim_sets.clone(),
+                });
+                variants.entry(&cred.id).or_default().push(id);
+            }
+        }
+
+        let single_format = variants.values().all(|ids| ids.len() == 1);
+        if self.credential_sets.is_empty() && single_format {
+            return DcqlQuery {
+                credentials,
+                credential_sets: None,
+            };
+        }
+        let sets = match self.credential_sets.is_empty() {
+            true => self
+                .credentials
+                .iter()
+                .map(|cred| CredentialSetQuery {
+                    options: vec![vec![cred.id.clone()]],
+                    required: true,
+                })
+                .collect(),
+            false => self.credential_sets.clone(),
+        };
+        let credential_sets = sets
+            .into_iter()
+            .map(|set| CredentialSetQuery {
+                options: set
+                    .options
+                    .iter()
+                    .flat_map(|option| expand_option(option, &variants))
+                    .collect(),
+                required: set.required,
+            })
+            .collect();
+        DcqlQuery {
+            credentials,
+            credential_sets: Some(credential_sets),
+        }
+    }
+}
+
+fn format_meta(format: &str, types: &[String]) -> Value {
+    match format {
+        "dc+sd-jwt" | "vc+sd-jwt" => json!({ "vct_values": types }),
+        _ => json!({ "type_values": [types] }),
+    }
+}
+
+fn query_id_safe(format: &str) -> String {
+    format
+        .chars()
+        .map(
+            |c| match c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                true => c,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+// An option naming a multi-format credential turns into one option per format
+fn expand_option(option: &[String], variants: &HashMap<&str, Vec<String>>) -> Vec<Vec<String>> {
+    option.iter().fold(vec![vec![]], |options, id| {
+        let choices = variants
+            .get(id.as_str())
+            .cloned()
+            .unwrap_or_else(|| vec![id.clone()]);
+        options
+            .iter()
+            .flat_map(|prefix| {
+                choices.iter().map(move |choice| {
+                    let mut option = prefix.clone();
+                    option.push(choice.clone());
+                    option
+                })
+            })
+            .collect()
+    })
+}
@@ -17,8 +17,6 @@
  *
  */
 
-mod dataspace_vc;
-mod identity_vc;
+mod did_document;
 
-pub use dataspace_vc::*;
-pub use identity_vc::*;
+pub use did_document::{DidDocument, VerificationMethod};
@@ -0,0 +1,73 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use tracing::error;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientIdScheme {
+    RedirectUri,
+    X509SanDns,
+    Did,
+}
+
+impl fmt::Display for ClientIdScheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientIdScheme::RedirectUri => write!(f, "redirect_uri"),
+            ClientIdScheme::X509SanDns => write!(f, "x509_san_dns"),
+            ClientIdScheme::Did => write!(f, "did"),
+        }
+    }
+}
+
+impl ClientIdScheme {
+    // OpenID4VP 1.0 carries the scheme as a prefix of the client id
+    pub fn client_id(&self, id: &str) -> String {
+        match self {
+            ClientIdScheme::RedirectUri => id.to_string(),
+            ClientIdScheme::X509SanDns => format!("x509_san_dns:{}", id),
+            ClientIdScheme::Did => format!("decentralized_identifier:{}", id),
+        }
+    }
+}
+
+impl FromStr for ClientIdScheme {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "redirect_uri" => Ok(ClientIdScheme::RedirectUri),
+            "x509_san_dns" => Ok(ClientIdScheme::X509SanDns),
+            "did" => Ok(ClientIdScheme::Did),
+            _ => {
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Unknown client id scheme: {}", s),
+                );
+                error!("{}", error.log());
+                Err(error.into())
+            }
+        }
+    }
+}
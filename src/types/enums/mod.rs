@@ -16,6 +16,14 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+pub mod client_id_scheme;
 pub mod errors;
+pub mod export_format;
+pub mod notifier_type;
+pub mod qr_format;
+pub mod query_language;
 pub mod request;
-pub mod vc_type;
+pub mod signer_type;
+pub mod signing_alg;
+pub mod unreachable_policy;
+pub mod vc_data_model;
@@ -19,38 +19,41 @@
 
 use crate::errors::{ErrorLogTrait, Errors};
 use crate::types::enums::errors::BadFormat;
-use anyhow::bail;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use tracing::error;
 
-pub enum VcType {
-    DataSpaceParticipant,
-    Identity,
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QueryLanguage {
+    Pex,
+    Dcql,
 }
 
-impl VcType {
-    pub fn to_conf(self) -> String {
+impl fmt::Display for QueryLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            VcType::DataSpaceParticipant => "DataspaceParticipantCredential_jwt_vc_json".to_string(),
-            VcType::Identity => "IdentityCredential_jwt_vc_json".to_string(),
+            QueryLanguage::Pex => write!(f, "pex"),
+            QueryLanguage::Dcql => write!(f, "dcql"),
         }
     }
+}
 
-    pub fn from_str(s: &str) -> anyhow::Result<VcType> {
-        match s {
-            "DataspaceParticipantCredential" => Ok(VcType::DataSpaceParticipant),
-            "IdentityCredential" => Ok(VcType::Identity),
+impl FromStr for QueryLanguage {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pex" => Ok(QueryLanguage::Pex),
+            "dcql" => Ok(QueryLanguage::Dcql),
             _ => {
-                let error = Errors::format_new(BadFormat::Received, &format!("Unknown format: {}", s));
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Unknown query language: {}", s),
+                );
                 error!("{}", error.log());
-                bail!(error)
+                Err(error.into())
             }
         }
     }
-
-    pub fn to_string(&self) -> String {
-        match self {
-            VcType::DataSpaceParticipant => "DataspaceParticipantCredential".to_string(),
-            VcType::Identity => "IdentityCredential".to_string(),
-        }
-    }
 }
@@ -0,0 +1,85 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use jsonwebtoken::Algorithm;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use tracing::error;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningAlgorithm {
+    RS256,
+    ES256,
+    ES384,
+    EdDSA,
+}
+
+impl SigningAlgorithm {
+    pub fn all() -> Vec<SigningAlgorithm> {
+        vec![
+            SigningAlgorithm::RS256,
+            SigningAlgorithm::ES256,
+            SigningAlgorithm::ES384,
+            SigningAlgorithm::EdDSA,
+        ]
+    }
+
+    pub fn to_jwt(self) -> Algorithm {
+        match self {
+            SigningAlgorithm::RS256 => Algorithm::RS256,
+            SigningAlgorithm::ES256 => Algorithm::ES256,
+            SigningAlgorithm::ES384 => Algorithm::ES384,
+            SigningAlgorithm::EdDSA => Algorithm::EdDSA,
+        }
+    }
+}
+
+impl fmt::Display for SigningAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SigningAlgorithm::RS256 => write!(f, "RS256"),
+            SigningAlgorithm::ES256 => write!(f, "ES256"),
+            SigningAlgorithm::ES384 => write!(f, "ES384"),
+            SigningAlgorithm::EdDSA => write!(f, "EdDSA"),
+        }
+    }
+}
+
+impl FromStr for SigningAlgorithm {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "RS256" => Ok(SigningAlgorithm::RS256),
+            "ES256" => Ok(SigningAlgorithm::ES256),
+            "ES384" => Ok(SigningAlgorithm::ES384),
+            "EdDSA" => Ok(SigningAlgorithm::EdDSA),
+            _ => {
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Unsupported signing algorithm: {}", s),
+                );
+                error!("{}", error.log());
+                Err(error.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use tracing::error;
+
+// What to do with a credential whose status list cannot be retrieved
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum UnreachablePolicy {
+    Reject,
+    Accept,
+}
+
+impl fmt::Display for UnreachablePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnreachablePolicy::Reject => write!(f, "reject"),
+            UnreachablePolicy::Accept => write!(f, "accept"),
+        }
+    }
+}
+
+impl FromStr for UnreachablePolicy {
+    type Err = anyhow::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reject" => Ok(UnreachablePolicy::Reject),
+            "accept" => Ok(UnreachablePolicy::Accept),
+            _ => {
+                let error = Errors::format_new(
+                    BadFormat::Unknown,
+                    &format!("Unknown unreachable status list policy: {}", s),
+                );
+                error!("{}", error.log());
+                Err(error.into())
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VcDataModel {
+    #[default]
+    #[serde(rename = "1.1")]
+    V1,
+    #[serde(rename = "2.0")]
+    V2,
+}
+
+impl VcDataModel {
+    pub fn context(&self) -> &'static str {
+        match self {
+            VcDataModel::V1 => "https://www.w3.org/2018/credentials/v1",
+            VcDataModel::V2 => "https://www.w3.org/ns/credentials/v2",
+        }
+    }
+
+    pub fn typ(&self) -> &'static str {
+        match self {
+            VcDataModel::V1 => "JWT",
+            VcDataModel::V2 => "vc+jwt",
+        }
+    }
+}
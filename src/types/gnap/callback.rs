@@ -28,3 +28,17 @@ pub struct CallbackBody {
 pub struct RejectedCallbackBody {
     pub rejected: String,
 }
+#[derive(Serialize)]
+pub struct RevokedCallbackBody {
+    pub revoked: String,
+    pub reason: Option<String>,
+    pub revoked_at: chrono::NaiveDateTime,
+}
+#[derive(Serialize)]
+pub struct ExpiringCallbackBody {
+    pub expiring: String,
+    pub vc_type: String,
+    pub credential_id: String,
+    pub expires_at: chrono::NaiveDateTime,
+    pub renewal_endpoint: String,
+}
@@ -189,6 +189,20 @@ impl AccessTokenRequirements4GR {
     }
 }
 
+impl Subject4GR {
+    // Subject identifiers follow RFC 9493, e.g. {"format": "email", "email": "..."}
+    pub fn email(&self) -> Option<String> {
+        self.sub_ids
+            .as_ref()?
+            .as_array()?
+            .iter()
+            .find(|id| id.get("format").and_then(|f| f.as_str()) == Some("email"))
+            .and_then(|id| id.get("email"))
+            .and_then(|email| email.as_str())
+            .map(|email| email.to_string())
+    }
+}
+
 impl Interact4GR {
     pub fn default4oidc(method: String, uri: Option<String>) -> Self {
         let nonce: String = rand::rng()
@@ -17,6 +17,7 @@
  *
  */
 
+use crate::types::credentials::{CredentialDisplay, CredentialRegistry};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,6 +27,8 @@ pub struct CredentialConfiguration {
     pub cryptographic_binding_methods_supported: Vec<String>,
     pub credential_signing_alg_values_supported: Vec<String>,
     pub credential_definition: CredentialDefinition,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub display: Vec<CredentialDisplay>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,33 +37,24 @@ pub struct CredentialDefinition {
 }
 
 impl CredentialConfiguration {
-    pub fn basic() -> HashMap<String, CredentialConfiguration> {
-        let mut credential_configurations_supported = HashMap::new();
-
-        credential_configurations_supported.insert(
-            "DataspaceParticipantCredential_jwt_vc_json".to_string(),
-            CredentialConfiguration {
-                format: "jwt_vc_json".to_string(),
-                cryptographic_binding_methods_supported: vec!["did".to_string()],
-                credential_signing_alg_values_supported: vec!["RSA".to_string()],
-                credential_definition: CredentialDefinition {
-                    r#type: vec!["VerifiableCredential".to_string(), "DataspaceParticipantCredential".to_string()],
-                },
-            },
-        );
-
-        credential_configurations_supported.insert(
-            "IdentityCredential_jwt_vc_json".to_string(),
-            CredentialConfiguration {
-                format: "jwt_vc_json".to_string(),
-                cryptographic_binding_methods_supported: vec!["did".to_string()],
-                credential_signing_alg_values_supported: vec!["RSA".to_string()],
-                credential_definition: CredentialDefinition {
-                    r#type: vec!["VerifiableCredential".to_string(), "IdentityCredential".to_string()],
-                },
-            },
-        );
-
-        credential_configurations_supported
+    pub fn from_registry(
+        registry: &CredentialRegistry,
+    ) -> HashMap<String, CredentialConfiguration> {
+        registry
+            .credentials
+            .iter()
+            .map(|cred| {
+                let conf = CredentialConfiguration {
+                    format: cred.format.clone(),
+                    cryptographic_binding_methods_supported: vec!["did".to_string()],
+                    credential_signing_alg_values_supported: vec!["RSA".to_string()],
+                    credential_definition: CredentialDefinition {
+                        r#type: vec!["VerifiableCredential".to_string(), cred.name.clone()],
+                    },
+                    display: cred.display.clone(),
+                };
+                (cred.conf_id(), conf)
+            })
+            .collect()
     }
 }
@@ -17,7 +17,7 @@
  *
  */
 
-use crate::types::enums::vc_type::VcType;
+use super::TX_CODE_LENGTH;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,14 +37,39 @@ pub struct CredOfferGrants {
 pub struct UrnPreAuthorizedCode {
     #[serde(rename = "pre-authorized_code")]
     pub pre_authorized_code: String,
+    pub tx_code: TxCode,
+}
+
+// Only describes the PIN, the PIN itself is delivered out-of-band
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TxCode {
+    pub input_mode: String,
+    pub length: usize,
+    pub description: String,
 }
 
 impl VCCredOffer {
-    pub fn new(issuer: String, token: String, vc_type: VcType) -> Self {
+    pub fn new(issuer: String, token: String, conf_id: String) -> Self {
         VCCredOffer {
             credential_issuer: issuer,
-            grants: CredOfferGrants { urn_pre_authorized_code: UrnPreAuthorizedCode { pre_authorized_code: token } },
-            credential_configuration_ids: vec![vc_type.to_conf()],
+            grants: CredOfferGrants {
+                urn_pre_authorized_code: UrnPreAuthorizedCode {
+                    pre_authorized_code: token,
+                    tx_code: TxCode::pin(),
+                },
+            },
+            credential_configuration_ids: vec![conf_id],
+        }
+    }
+}
+
+impl TxCode {
+    pub fn pin() -> Self {
+        TxCode {
+            input_mode: "numeric".to_string(),
+            length: TX_CODE_LENGTH,
+            description: "Enter the PIN sent to the contact given in your credential request"
+                .to_string(),
         }
     }
 }
@@ -27,4 +27,5 @@ pub struct DidPossession {
     pub jti: String,
     pub iat: u64,
     pub exp: u64,
+    pub nonce: Option<String>,
 }
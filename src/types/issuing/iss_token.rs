@@ -17,17 +17,26 @@
  *
  */
 
+use super::{NonceResponse, ACCESS_TOKEN_TTL};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct IssuingToken {
     pub access_token: String,
     pub token_type: String,
-    pub expires_in: u16,
+    pub expires_in: i64,
+    pub c_nonce: String,
+    pub c_nonce_expires_in: i64,
 }
 
 impl IssuingToken {
-    pub fn new(token: String) -> IssuingToken {
-        IssuingToken { access_token: token, token_type: "Bearer".to_string(), expires_in: 600 }
+    pub fn new(token: String, nonce: NonceResponse) -> IssuingToken {
+        IssuingToken {
+            access_token: token,
+            token_type: "DPoP".to_string(),
+            expires_in: ACCESS_TOKEN_TTL,
+            c_nonce: nonce.c_nonce,
+            c_nonce_expires_in: nonce.c_nonce_expires_in,
+        }
     }
 }
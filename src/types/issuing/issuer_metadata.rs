@@ -18,6 +18,7 @@
  */
 
 use super::CredentialConfiguration;
+use crate::types::credentials::CredentialRegistry;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -26,19 +27,23 @@ pub struct IssuerMetadata {
     pub issuer: String,
     pub credential_issuer: String,
     pub credential_endpoint: String,
+    pub nonce_endpoint: String,
+    pub notification_endpoint: String,
     pub jwks_uri: String,
     pub credential_configurations_supported: HashMap<String, CredentialConfiguration>,
     pub authorization_servers: Vec<String>,
 }
 
 impl IssuerMetadata {
-    pub fn new(host: &str) -> Self {
-        let credential_configurations_supported = CredentialConfiguration::basic();
+    pub fn new(host: &str, registry: &CredentialRegistry) -> Self {
+        let credential_configurations_supported = CredentialConfiguration::from_registry(registry);
 
         IssuerMetadata {
             issuer: host.to_string(),
             credential_issuer: host.to_string(),
             credential_endpoint: format!("{}/credential", host),
+            nonce_endpoint: format!("{}/nonce", host),
+            notification_endpoint: format!("{}/notification", host),
             jwks_uri: format!("{}/jwks", host),
             credential_configurations_supported,
             authorization_servers: vec![host.to_string()],
@@ -23,6 +23,8 @@ pub use cred_req::*;
 pub use did_possession::*;
 pub use iss_token::*;
 pub use issuer_metadata::*;
+pub use nonce::*;
+pub use notification::*;
 pub use oauth_server::*;
 pub use token_req::*;
 pub use vc_issuing::*;
@@ -34,7 +36,20 @@ mod cred_req;
 mod did_possession;
 mod iss_token;
 mod issuer_metadata;
+mod nonce;
+mod notification;
 mod oauth_server;
 mod token_req;
 mod vc_issuing;
 mod well_known_jwk;
+
+// Seconds a c_nonce can be used in a credential request proof
+pub const C_NONCE_TTL: i64 = 300;
+// Digits of the PIN sent out-of-band to redeem a credential offer
+pub const TX_CODE_LENGTH: usize = 6;
+// Wrong PINs accepted before the credential offer is locked
+pub const MAX_TX_CODE_ATTEMPTS: i32 = 5;
+// Seconds a credential offer can be redeemed at the token endpoint
+pub const PRE_AUTH_CODE_TTL: i64 = 3600;
+// Seconds an issuer access token can be used at the credential endpoint
+pub const ACCESS_TOKEN_TTL: i64 = 600;
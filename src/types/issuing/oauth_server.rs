@@ -18,6 +18,8 @@
  */
 
 use super::CredentialConfiguration;
+use crate::types::credentials::CredentialRegistry;
+use crate::types::enums::signing_alg::SigningAlgorithm;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -39,13 +41,14 @@ pub struct AuthServerMetadata {
     pub subject_types_supported: Vec<String>,
     pub id_token_signing_alg_values_supported: Vec<String>,
     pub code_challenge_methods_supported: Vec<String>,
+    pub dpop_signing_alg_values_supported: Vec<String>,
     pub credential_configurations_supported: HashMap<String, CredentialConfiguration>,
     pub authorization_servers: Vec<String>,
 }
 
 impl AuthServerMetadata {
-    pub fn new(host: &str) -> Self {
-        let credential_configurations_supported = CredentialConfiguration::basic();
+    pub fn new(host: &str, registry: &CredentialRegistry) -> Self {
+        let credential_configurations_supported = CredentialConfiguration::from_registry(registry);
 
         AuthServerMetadata {
             issuer: host.to_string(),
@@ -67,6 +70,10 @@ impl AuthServerMetadata {
             subject_types_supported: vec!["public".to_string()],
             id_token_signing_alg_values_supported: vec!["RSA".to_string()],
             code_challenge_methods_supported: vec!["S256".to_string()],
+            dpop_signing_alg_values_supported: SigningAlgorithm::all()
+                .iter()
+                .map(|alg| alg.to_string())
+                .collect(),
             credential_configurations_supported,
             authorization_servers: vec![host.to_string()],
         }
@@ -23,4 +23,6 @@ use serde::{Deserialize, Serialize};
 pub struct GiveVC {
     pub format: String,
     pub credential: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_id: Option<String>,
 }
@@ -17,29 +17,16 @@
  *
  */
 
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
-use base64::Engine;
-use rsa::traits::{PrivateKeyParts, PublicKeyParts};
-use rsa::RsaPrivateKey;
+use jsonwebtoken::jwk::Jwk;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct WellKnownJwks {
-    pub kty: String,
-    pub n: String,
-    pub e: String,
-    pub d: String,
-    pub kid: String,
+    pub keys: Vec<Jwk>,
 }
 
 impl WellKnownJwks {
-    pub fn new(key: RsaPrivateKey) -> WellKnownJwks {
-        WellKnownJwks {
-            kty: "RSA".to_string(),
-            n: URL_SAFE_NO_PAD.encode(key.n().to_bytes_be()),
-            e: URL_SAFE_NO_PAD.encode(key.e().to_bytes_be()),
-            d: URL_SAFE_NO_PAD.encode(key.d().to_bytes_be()),
-            kid: "0".to_string(),
-        }
+    pub fn new(keys: Vec<Jwk>) -> WellKnownJwks {
+        WellKnownJwks { keys }
     }
 }
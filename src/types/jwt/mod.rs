@@ -17,5 +17,14 @@
  *
  */
 
+mod access_token_claims;
+mod dpop_claims;
 mod jwt_claims;
+pub use access_token_claims::{AccessTokenClaims, Confirmation};
+pub use dpop_claims::DpopClaims;
 pub use jwt_claims::AuthJwtClaims;
+
+// Seconds a DPoP proof is accepted after its iat
+pub const DPOP_PROOF_TTL: i64 = 300;
+// Seconds a server provided DPoP nonce stays valid
+pub const DPOP_NONCE_TTL: i64 = 300;
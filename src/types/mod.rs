@@ -17,11 +17,19 @@
  *
  */
 
+pub mod credentials;
+pub mod dcql;
+pub mod did;
 pub mod enums;
 pub mod gnap;
 pub mod host;
 pub mod issuing;
 pub mod jwt;
+pub mod ledger;
+pub mod minions;
+pub mod notifier;
+pub mod status_list;
+pub mod trusted_issuers;
 pub mod vcs;
 pub mod verifying;
 pub mod wallet;
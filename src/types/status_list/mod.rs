@@ -0,0 +1,31 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+mod status_purpose;
+mod status_update;
+
+pub use status_purpose::StatusPurpose;
+pub use status_update::StatusUpdate;
+
+// Minimum length required by the Bitstring Status List spec (16KB) to provide group privacy
+pub const STATUS_LIST_SIZE: i64 = 131_072;
+// Time (ms) verifiers are allowed to cache a status list credential
+pub const STATUS_LIST_TTL: u64 = 300_000;
+// Largest decompressed status list accepted from an issuer (16MB, about 134M entries)
+pub const MAX_STATUS_LIST_BYTES: u64 = 16_777_216;
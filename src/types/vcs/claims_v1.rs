@@ -17,7 +17,7 @@
  *
  */
 
-use crate::types::vcs::VCIssuer;
+use crate::types::vcs::{CredentialSchema, VCIssuer};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -29,6 +29,10 @@ pub struct VCClaimsV1 {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iat: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iss: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub: Option<String>,
@@ -41,12 +45,15 @@ pub struct VCFromClaimsV1 {
     pub context: Vec<String>,
     pub r#type: Vec<String>,
     pub id: String,
-    #[serde(rename = "CredentialSubject")]
+    #[serde(rename = "credentialSubject")]
     pub credential_subject: Value,
     pub issuer: VCIssuer,
-    #[serde(rename = "validFrom", skip_serializing_if = "Option::is_none")]
-    pub valid_from: Option<DateTime<Utc>>,
-    #[serde()]
-    #[serde(rename = "validUntil", skip_serializing_if = "Option::is_none")]
-    pub valid_until: Option<DateTime<Utc>>,
+    #[serde(rename = "issuanceDate")]
+    pub issuance_date: DateTime<Utc>,
+    #[serde(rename = "expirationDate", skip_serializing_if = "Option::is_none")]
+    pub expiration_date: Option<DateTime<Utc>>,
+    #[serde(rename = "credentialStatus", skip_serializing_if = "Option::is_none")]
+    pub credential_status: Option<Value>,
+    #[serde(rename = "credentialSchema", skip_serializing_if = "Option::is_none")]
+    pub credential_schema: Option<CredentialSchema>,
 }
@@ -17,7 +17,7 @@
  *
  */
 
-use crate::types::vcs::VCIssuer;
+use crate::types::vcs::{CredentialSchema, VCIssuer};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -29,6 +29,8 @@ pub struct VCClaimsV2 {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub iat: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub nbf: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub iss: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub: Option<String>,
@@ -36,7 +38,7 @@ pub struct VCClaimsV2 {
     pub context: Vec<String>,
     pub r#type: Vec<String>,
     pub id: String,
-    #[serde(rename = "CredentialSubject")]
+    #[serde(rename = "credentialSubject")]
     pub credential_subject: Value,
     pub issuer: VCIssuer,
     #[serde(rename = "validFrom", skip_serializing_if = "Option::is_none")]
@@ -44,4 +46,8 @@ pub struct VCClaimsV2 {
     #[serde()]
     #[serde(rename = "validUntil", skip_serializing_if = "Option::is_none")]
     pub valid_until: Option<DateTime<Utc>>,
+    #[serde(rename = "credentialStatus", skip_serializing_if = "Option::is_none")]
+    pub credential_status: Option<Value>,
+    #[serde(rename = "credentialSchema", skip_serializing_if = "Option::is_none")]
+    pub credential_schema: Option<CredentialSchema>,
 }
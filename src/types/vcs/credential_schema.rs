@@ -19,16 +19,17 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct CredentialSubject4Identity {
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CredentialSchema {
     pub id: String,
     pub r#type: String,
-    #[serde(rename = "LegalName")]
-    pub legal_name: String,
 }
 
-impl CredentialSubject4Identity {
-    pub fn new(id: String, legal_name: String) -> Self {
-        CredentialSubject4Identity { id, r#type: "IdentityCredential".to_string(), legal_name }
+impl CredentialSchema {
+    pub fn new(schema_uri: &str) -> Self {
+        CredentialSchema {
+            id: schema_uri.to_string(),
+            r#type: "JsonSchema".to_string(),
+        }
     }
 }
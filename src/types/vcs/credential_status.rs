@@ -0,0 +1,45 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::types::status_list::StatusPurpose;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BitstringStatusListEntry {
+    pub id: String,
+    pub r#type: String,
+    #[serde(rename = "statusPurpose")]
+    pub status_purpose: StatusPurpose,
+    #[serde(rename = "statusListIndex")]
+    pub status_list_index: String,
+    #[serde(rename = "statusListCredential")]
+    pub status_list_credential: String,
+}
+
+impl BitstringStatusListEntry {
+    pub fn new(list_uri: &str, index: i64, purpose: StatusPurpose) -> Self {
+        BitstringStatusListEntry {
+            id: format!("{}#{}", list_uri, index),
+            r#type: "BitstringStatusListEntry".to_string(),
+            status_purpose: purpose,
+            status_list_index: index.to_string(),
+            status_list_credential: list_uri.to_string(),
+        }
+    }
+}
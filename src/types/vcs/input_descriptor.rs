@@ -17,6 +17,8 @@
  *
  */
 
+use crate::types::credentials::CredentialType;
+use crate::types::enums::signing_alg::SigningAlgorithm;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -24,11 +26,30 @@ pub struct InputDescriptor {
     pub id: String,
     pub format: InputDescriptorFormat,
     pub constraints: InputDescriptorConstraints,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InputDescriptorFormat {
-    jwt_vc_json: InputDescriptorFormatJWTJson,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jwt_vc_json: Option<InputDescriptorFormatJWTJson>,
+    #[serde(rename = "vc+sd-jwt", skip_serializing_if = "Option::is_none")]
+    vc_sd_jwt: Option<InputDescriptorFormatSdJwt>,
+}
+
+impl InputDescriptorFormat {
+    pub fn accepts(&self, format: &str) -> bool {
+        match format {
+            "jwt_vc_json" | "jwt_vc" => self.jwt_vc_json.is_some(),
+            "vc+sd-jwt" | "dc+sd-jwt" => self.vc_sd_jwt.is_some(),
+            _ => false,
+        }
+    }
+
+    pub fn is_sd_jwt(format: &str) -> bool {
+        matches!(format, "vc+sd-jwt" | "dc+sd-jwt")
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,15 +57,27 @@ pub struct InputDescriptorFormatJWTJson {
     pub alg: Vec<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InputDescriptorFormatSdJwt {
+    #[serde(rename = "sd-jwt_alg_values")]
+    pub sd_jwt_alg_values: Vec<String>,
+    #[serde(rename = "kb-jwt_alg_values")]
+    pub kb_jwt_alg_values: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InputDescriptorConstraints {
     pub fields: Vec<InputDescriptorConstraintsFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_disclosure: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct InputDescriptorConstraintsFields {
     pub path: Vec<String>,
     pub filter: InputDescriptorConstraintsFieldsFilter,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -54,23 +87,48 @@ pub struct InputDescriptorConstraintsFieldsFilter {
 }
 
 impl InputDescriptor {
-    pub fn new(vc_type: String) -> Self {
+    pub fn new(cred_type: &CredentialType) -> Self {
+        // SD-JWT VCs name their type in "vct" instead of a "type" array
+        let (format, path) = match InputDescriptorFormat::is_sd_jwt(&cred_type.format) {
+            true => {
+                let algs: Vec<String> = SigningAlgorithm::all()
+                    .iter()
+                    .map(|alg| alg.to_string())
+                    .collect();
+                let format = InputDescriptorFormat {
+                    jwt_vc_json: None,
+                    vc_sd_jwt: Some(InputDescriptorFormatSdJwt {
+                        sd_jwt_alg_values: algs.clone(),
+                        kb_jwt_alg_values: algs,
+                    }),
+                };
+                (format, vec!["$.vct".to_string()])
+            }
+            false => {
+                let format = InputDescriptorFormat {
+                    jwt_vc_json: Some(InputDescriptorFormatJWTJson {
+                        alg: vec!["RSA".to_string()],
+                    }),
+                    vc_sd_jwt: None,
+                };
+                (format, vec!["$.vc.type".to_string(), "$.type".to_string()])
+            }
+        };
         InputDescriptor {
-            id: vc_type.clone(),
-            format: InputDescriptorFormat {
-                jwt_vc_json: InputDescriptorFormatJWTJson {
-                    alg: vec!["RSA".to_string()],
-                },
-            },
+            id: cred_type.name.clone(),
+            format,
             constraints: InputDescriptorConstraints {
                 fields: vec![InputDescriptorConstraintsFields {
-                    path: vec!["$.vc.type".to_string()],
+                    path,
                     filter: InputDescriptorConstraintsFieldsFilter {
                         r#type: "string".to_string(),
-                        pattern: vc_type,
+                        pattern: cred_type.name.clone(),
                     },
+                    optional: None,
                 }],
+                limit_disclosure: None,
             },
+            group: None,
         }
     }
 }
@@ -18,8 +18,12 @@
  */
 mod claims_v1;
 mod claims_v2;
-pub mod cred_subject;
+mod credential_schema;
+mod credential_status;
 mod input_descriptor;
+mod presentation_submission;
+mod sd_jwt;
+mod submission_requirement;
 mod vc_decision_approval;
 mod vc_issuer;
 mod vci_data;
@@ -27,7 +31,12 @@ mod vpd;
 
 pub use claims_v1::*;
 pub use claims_v2::*;
+pub use credential_schema::*;
+pub use credential_status::*;
 pub use input_descriptor::*;
+pub use presentation_submission::*;
+pub use sd_jwt::SdJwt;
+pub use submission_requirement::*;
 pub use vc_decision_approval::*;
 pub use vc_issuer::*;
 pub use vci_data::VCIData;
@@ -20,22 +20,19 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
-pub struct CredentialSubject4DataSpace {
+pub struct PresentationSubmission {
     pub id: String,
-    pub r#type: String,
-    #[serde(rename = "DataspaceId")]
-    pub dataspace_id: String,
-    #[serde(rename = "LegalName")]
-    pub legal_name: String,
+    pub definition_id: String,
+    pub descriptor_map: Vec<DescriptorMap>,
 }
 
-impl CredentialSubject4DataSpace {
-    pub fn new(id: String, legal_name: String) -> Self {
-        CredentialSubject4DataSpace {
-            id,
-            r#type: "DataspaceParticipant".to_string(),
-            dataspace_id: "RainbowDataSpace".to_string(),
-            legal_name,
-        }
-    }
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DescriptorMap {
+    // Nested entries only carry format & path
+    #[serde(default)]
+    pub id: String,
+    pub format: String,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path_nested: Option<Box<DescriptorMap>>,
 }
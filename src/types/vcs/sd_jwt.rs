@@ -0,0 +1,187 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::errors::{ErrorLogTrait, Errors};
+use crate::types::enums::errors::BadFormat;
+use crate::utils::sha256_digest;
+use anyhow::bail;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde_json::Value;
+use std::collections::HashMap;
+use tracing::error;
+
+// SD-JWT presentation: <issuer signed JWT>~<disclosure>~...~<key binding JWT>
+#[derive(Debug, Clone)]
+pub struct SdJwt {
+    pub issuer_jwt: String,
+    pub disclosures: Vec<String>,
+    pub kb_jwt: Option<String>,
+}
+
+impl SdJwt {
+    pub fn is_sd_jwt(presentation: &str) -> bool {
+        presentation.contains('~')
+    }
+
+    pub fn parse(presentation: &str) -> anyhow::Result<Self> {
+        let mut parts: Vec<&str> = presentation.split('~').collect();
+        if parts.len() < 2 || parts[0].is_empty() {
+            let error = Errors::format_new(BadFormat::Received, "Presentation is not an SD-JWT");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        let kb_jwt = parts.pop().filter(|kb_jwt| !kb_jwt.is_empty());
+        let issuer_jwt = parts.remove(0);
+        if parts.iter().any(|disclosure| disclosure.is_empty()) {
+            let error =
+                Errors::format_new(BadFormat::Received, "SD-JWT contains an empty disclosure");
+            error!("{}", error.log());
+            bail!(error)
+        }
+        Ok(SdJwt {
+            issuer_jwt: issuer_jwt.to_string(),
+            disclosures: parts.into_iter().map(str::to_string).collect(),
+            kb_jwt: kb_jwt.map(str::to_string),
+        })
+    }
+
+    // Digest of the presentation up to the key binding JWT, last '~' included
+    pub fn sd_hash(&self) -> String {
+        let mut presented = format!("{}~", self.issuer_jwt);
+        for disclosure in &self.disclosures {
+            presented.push_str(disclosure);
+            presented.push('~');
+        }
+        sha256_digest(&presented)
+    }
+
+    // Puts the disclosed claims in place of their digests, undisclosed ones are left out
+    pub fn disclose(&self, claims: &Value) -> anyhow::Result<Value> {
+        if let Some(alg) = claims.get("_sd_alg") {
+            if alg != "sha-256" {
+                return invalid(&format!("Digest algorithm {} is not supported", alg));
+            }
+        }
+        let mut disclosures = HashMap::new();
+        for disclosure in &self.disclosures {
+            let decoded = URL_SAFE_NO_PAD
+                .decode(disclosure)
+                .ok()
+                .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok());
+            let decoded = match decoded {
+                Some(Value::Array(items)) if matches!(items.len(), 2 | 3) => items,
+                _ => return invalid(&format!("Disclosure {} is malformed", disclosure)),
+            };
+            if disclosures
+                .insert(sha256_digest(disclosure), decoded)
+                .is_some()
+            {
+                return invalid(&format!("Disclosure {} is repeated", disclosure));
+            }
+        }
+
+        let mut disclosed = claims.clone();
+        if let Value::Object(map) = &mut disclosed {
+            map.remove("_sd_alg");
+        }
+        resolve(&mut disclosed, &mut disclosures)?;
+        if !disclosures.is_empty() {
+            return invalid(&format!(
+                "{} disclosures are not referenced by the credential",
+                disclosures.len()
+            ));
+        }
+        Ok(disclosed)
+    }
+}
+
+fn resolve(node: &mut Value, disclosures: &mut HashMap<String, Vec<Value>>) -> anyhow::Result<()> {
+    match node {
+        Value::Object(map) => {
+            if let Some(digests) = map.remove("_sd") {
+                let digests = match digests {
+                    Value::Array(digests) => digests,
+                    _ => return invalid("_sd is not an array of digests"),
+                };
+                for digest in digests {
+                    // Digests without a disclosure are withheld claims or decoys
+                    let disclosure = match digest.as_str().and_then(|d| disclosures.remove(d)) {
+                        Some(disclosure) => disclosure,
+                        None => continue,
+                    };
+                    match disclosure.as_slice() {
+                        [_, Value::String(name), value] => {
+                            if name == "_sd" || name == "..." || map.contains_key(name) {
+                                return invalid(&format!(
+                                    "Disclosed claim {} is not allowed",
+                                    name
+                                ));
+                            }
+                            map.insert(name.clone(), value.clone());
+                        }
+                        _ => return invalid("Object digest points to an array element disclosure"),
+                    }
+                }
+            }
+            for value in map.values_mut() {
+                resolve(value, disclosures)?;
+            }
+        }
+        Value::Array(items) => {
+            let mut kept = Vec::new();
+            for mut item in items.drain(..) {
+                let digest = item
+                    .as_object()
+                    .filter(|object| object.len() == 1)
+                    .and_then(|object| object.get("..."))
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+                let digest = match digest {
+                    Some(digest) => digest,
+                    None => {
+                        resolve(&mut item, disclosures)?;
+                        kept.push(item);
+                        continue;
+                    }
+                };
+                match disclosures.remove(&digest).as_deref() {
+                    Some([_, value]) => {
+                        let mut value = value.clone();
+                        resolve(&mut value, disclosures)?;
+                        kept.push(value);
+                    }
+                    Some(_) => {
+                        return invalid("Array digest points to an object property disclosure")
+                    }
+                    None => {}
+                }
+            }
+            *items = kept;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn invalid<T>(cause: &str) -> anyhow::Result<T> {
+    let error = Errors::security_new(cause);
+    error!("{}", error.log());
+    bail!(error)
+}
@@ -0,0 +1,66 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmissionRequirement {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub rule: SubmissionRule,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_nested: Option<Vec<SubmissionRequirement>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionRule {
+    All,
+    Pick,
+}
+
+impl SubmissionRequirement {
+    pub fn label(&self) -> String {
+        match (&self.name, &self.from) {
+            (Some(name), _) => name.clone(),
+            (None, Some(from)) => format!("group {}", from),
+            (None, None) => "nested requirement".to_string(),
+        }
+    }
+
+    // Counts satisfied members against the rule, members being descriptors or nested requirements
+    pub fn accepts(&self, satisfied: usize, total: usize) -> bool {
+        match self.rule {
+            SubmissionRule::All => satisfied == total,
+            SubmissionRule::Pick => {
+                self.count.is_none_or(|count| satisfied >= count)
+                    && self.min.is_none_or(|min| satisfied >= min)
+                    && self.max.is_none_or(|max| satisfied <= max)
+            }
+        }
+    }
+}
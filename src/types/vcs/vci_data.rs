@@ -17,10 +17,8 @@
  *
  */
 
-use crate::types::enums::vc_type::VcType;
-
 pub struct VCIData {
     pub name: String,
     pub website: String,
-    pub vc_type: VcType,
+    pub vc_type: String,
 }
@@ -17,20 +17,25 @@
  *
  */
 
+use crate::types::credentials::CredentialType;
 use crate::types::vcs::input_descriptor::InputDescriptor;
+use crate::types::vcs::SubmissionRequirement;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct VPDef {
     pub id: String,
     pub input_descriptors: Vec<InputDescriptor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submission_requirements: Option<Vec<SubmissionRequirement>>,
 }
 
 impl VPDef {
-    pub fn new(id: String, vc_type: String) -> Self {
+    pub fn new(id: String, cred_type: &CredentialType) -> Self {
         VPDef {
             id,
-            input_descriptors: vec![InputDescriptor::new(vc_type)],
+            input_descriptors: vec![InputDescriptor::new(cred_type)],
+            submission_requirements: None,
         }
     }
 }
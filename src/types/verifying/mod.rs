@@ -16,5 +16,9 @@
  *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
  *
  */
+mod request_object;
+mod verification_status;
 mod verify_payload;
+pub use request_object::{ClientMetadata, RequestObject};
+pub use verification_status::{VerificationPhase, VerificationStatus};
 pub use verify_payload::VerifyPayload;
@@ -0,0 +1,54 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::types::dcql::DcqlQuery;
+use crate::types::vcs::VPDef;
+use serde::Serialize;
+use serde_json::Value;
+
+// Signed authorization request passed to the wallet by reference (JAR)
+#[derive(Serialize, Debug)]
+pub struct RequestObject {
+    pub iss: String,
+    pub aud: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub client_id: String,
+    pub response_type: String,
+    pub response_mode: String,
+    pub response_uri: String,
+    pub nonce: String,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_definition: Option<VPDef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dcql_query: Option<DcqlQuery>,
+    pub client_metadata: ClientMetadata,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ClientMetadata {
+    pub vp_formats: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwks: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_encrypted_response_alg: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization_encrypted_response_enc: Option<String>,
+}
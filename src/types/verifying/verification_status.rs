@@ -0,0 +1,64 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use crate::data::entities::verification;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationPhase {
+    Pending,
+    Succeeded,
+    Failed,
+    Expired,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VerificationStatus {
+    pub state: String,
+    pub status: VerificationPhase,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ended_at: Option<NaiveDateTime>,
+}
+
+impl VerificationStatus {
+    pub fn is_final(&self) -> bool {
+        self.status != VerificationPhase::Pending
+    }
+}
+
+impl From<&verification::Model> for VerificationStatus {
+    fn from(model: &verification::Model) -> Self {
+        let status = match model.status.as_str() {
+            "Succeeded" => VerificationPhase::Succeeded,
+            "Failed" => VerificationPhase::Failed,
+            "Expired" => VerificationPhase::Expired,
+            _ => VerificationPhase::Pending,
+        };
+        Self {
+            state: model.state.clone(),
+            status,
+            reason: model.reason.clone(),
+            ended_at: model.ended_at,
+        }
+    }
+}
@@ -21,6 +21,9 @@ use serde::Deserialize;
 
 #[derive(Deserialize)]
 pub struct VerifyPayload {
-    pub vp_token: String,
-    pub presentation_submission: String,
+    pub vp_token: Option<String>,
+    // DCQL responses come without a submission
+    pub presentation_submission: Option<String>,
+    // direct_post.jwt wraps the whole response in a JWE
+    pub response: Option<String>,
 }
@@ -17,24 +17,54 @@
  *
  */
 
+#[cfg(test)]
+mod tests;
+mod ttl_set;
+
+pub use ttl_set::TtlSet;
+
 use crate::errors::{ErrorLogTrait, Errors};
+use crate::services::resolver::DidResolverTrait;
 use crate::types::enums::errors::BadFormat;
+use crate::types::enums::qr_format::QrFormat;
+use crate::types::enums::signing_alg::SigningAlgorithm;
+use crate::types::jwt::{DpopClaims, DPOP_PROOF_TTL};
+use crate::types::status_list::MAX_STATUS_LIST_BYTES;
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
 use anyhow::bail;
 use axum::http::HeaderMap;
-use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
 use base64::Engine;
 use chrono::Utc;
-use jsonwebtoken::jwk::Jwk;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use image::{DynamicImage, ImageFormat, Luma};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::ThumbprintHash;
 use jsonwebtoken::{TokenData, Validation};
+use p256::elliptic_curve::sec1::{EncodedPoint, ToEncodedPoint};
+use qrcode::render::svg;
+use qrcode::QrCode;
 use rand::Rng;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use serde_json::Value;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::fs;
+use std::io::{Cursor, Read, Write};
 use std::path::PathBuf;
 use tracing::{error, info};
 
+pub fn create_pin(length: usize) -> String {
+    let mut rng = rand::rng();
+    (0..length)
+        .map(|_| char::from(b'0' + rng.random_range(0..10)))
+        .collect()
+}
+
 pub fn create_opaque_token() -> String {
     let mut bytes = [0u8; 32]; // 256 bits
     rand::rng().fill(&mut bytes);
@@ -57,6 +87,43 @@ pub fn extract_bearer_token(headers: HeaderMap) -> Option<String> {
         .map(|token| token.to_string())
 }
 
+pub fn extract_dpop_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|s| s.strip_prefix("DPoP "))
+        .map(|token| token.to_string())
+}
+
+pub fn extract_dpop_proof(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("DPoP")
+        .and_then(|value| value.to_str().ok())
+        .map(|proof| proof.to_string())
+}
+
+pub fn sha256_digest(data: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(data.as_bytes()))
+}
+
+// Fields are always quoted so commas, quotes and newlines survive the export
+pub fn to_csv(headers: &[&str], rows: Vec<Vec<String>>) -> String {
+    let line = |fields: Vec<String>| {
+        fields
+            .iter()
+            .map(|field| format!("\"{}\"", field.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let mut csv = line(headers.iter().map(|header| header.to_string()).collect());
+    for row in rows {
+        csv.push_str("\r\n");
+        csv.push_str(&line(row));
+    }
+    csv.push_str("\r\n");
+    csv
+}
+
 pub fn trim_4_base(input: &str) -> String {
     let slashes: Vec<usize> = input.match_indices('/').map(|(i, _)| i).collect();
 
@@ -129,9 +196,11 @@ fn validate_data(node: &Value, field: &str) -> anyhow::Result<String> {
     }
 }
 
-pub fn validate_token<T>(
+pub async fn validate_token<T>(
     token: &str,
     audience: Option<&str>,
+    resolver: &dyn DidResolverTrait,
+    leeway: u64,
 ) -> anyhow::Result<(TokenData<T>, String)>
 where
     T: Serialize + DeserializeOwned,
@@ -139,19 +208,20 @@ where
     info!("Validating token");
     let header = jsonwebtoken::decode_header(&token)?;
     let kid_str = get_from_opt(&header.kid, "kid")?;
-    let (kid, _) = split_did(kid_str.as_str()); // TODO KID_ID
+    let (kid, _) = split_did(kid_str.as_str());
     let alg = header.alg;
 
-    let vec = URL_SAFE_NO_PAD.decode(&(kid.replace("did:jwk:", "")))?;
-    let jwk: Jwk = serde_json::from_slice(&vec)?;
+    let jwk = resolver.resolve_key(&kid_str).await?;
 
     let key = jsonwebtoken::DecodingKey::from_jwk(&jwk)?;
 
     let mut val = Validation::new(alg);
 
+    // exp and nbf are checked whenever present, with some tolerance for clock drift
     val.required_spec_claims = HashSet::new();
-    val.validate_exp = false;
+    val.validate_exp = true;
     val.validate_nbf = true;
+    val.leeway = leeway;
 
     match audience {
         Some(data) => {
@@ -166,8 +236,11 @@ where
     let token_data = match jsonwebtoken::decode::<T>(&token, &key, &val) {
         Ok(data) => data,
         Err(e) => {
-            let error =
-                Errors::security_new(&format!("VPT signature is incorrect -> {}", e.to_string()));
+            let error = match e.kind() {
+                ErrorKind::ExpiredSignature => Errors::security_new("Token has expired"),
+                ErrorKind::ImmatureSignature => Errors::security_new("Token is not valid yet"),
+                _ => Errors::security_new(&format!("VPT signature is incorrect -> {}", e)),
+            };
             error!("{}", error.log());
             bail!(error);
         }
@@ -177,6 +250,255 @@ where
     Ok((token_data, kid.to_string()))
 }
 
+// Multikeys are base58btc strings prefixed with the multicodec of the key type
+pub fn multikey_to_jwk(multikey: &str) -> anyhow::Result<Value> {
+    let bytes = match multikey
+        .strip_prefix('z')
+        .map(|data| bs58::decode(data).into_vec())
+    {
+        Some(Ok(bytes)) => bytes,
+        _ => {
+            let error = Errors::format_new(
+                BadFormat::Received,
+                "Key is not a base58btc multibase value",
+            );
+            error!("{}", error.log());
+            bail!(error)
+        }
+    };
+
+    match bytes.as_slice() {
+        [0xed, 0x01, key @ ..] if key.len() == 32 => Ok(json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "x": URL_SAFE_NO_PAD.encode(key),
+        })),
+        [0x80, 0x24, key @ ..] => {
+            let point = p256::PublicKey::from_sec1_bytes(key)?.to_encoded_point(false);
+            Ok(ec_jwk("P-256", point.x(), point.y()))
+        }
+        [0xe7, 0x01, key @ ..] => {
+            let point = k256::PublicKey::from_sec1_bytes(key)?.to_encoded_point(false);
+            Ok(ec_jwk("secp256k1", point.x(), point.y()))
+        }
+        _ => {
+            let error = Errors::format_new(BadFormat::Received, "Unsupported multikey type");
+            error!("{}", error.log());
+            bail!(error)
+        }
+    }
+}
+
+fn ec_jwk<C: AsRef<[u8]>>(crv: &str, x: Option<&C>, y: Option<&C>) -> Value {
+    let encode = |coord: Option<&C>| coord.map(|c| URL_SAFE_NO_PAD.encode(c)).unwrap_or_default();
+    json!({
+        "kty": "EC",
+        "crv": crv,
+        "x": encode(x),
+        "y": encode(y),
+    })
+}
+
+// Checks a DPoP proof (RFC 9449) and returns it with the thumbprint of the key that signed it
+pub fn validate_dpop_proof(
+    proof: &str,
+    htm: &str,
+    htu: &str,
+    access_token: Option<&str>,
+) -> anyhow::Result<(DpopClaims, String)> {
+    info!("Validating DPoP proof");
+
+    let header = match jsonwebtoken::decode_header(proof) {
+        Ok(data) => data,
+        Err(e) => return dpop_error(&format!("DPoP proof is not a JWT -> {}", e)),
+    };
+    if header.typ.as_deref() != Some("dpop+jwt") {
+        return dpop_error("DPoP proof must have typ 'dpop+jwt'");
+    }
+    if !SigningAlgorithm::all()
+        .iter()
+        .any(|alg| alg.to_jwt() == header.alg)
+    {
+        return dpop_error(&format!(
+            "DPoP proof algorithm {:?} not supported",
+            header.alg
+        ));
+    }
+    let jwk = match header.jwk {
+        Some(data) => data,
+        None => return dpop_error("DPoP proof does not carry its public jwk"),
+    };
+
+    let key = match jsonwebtoken::DecodingKey::from_jwk(&jwk) {
+        Ok(data) => data,
+        Err(e) => return dpop_error(&format!("DPoP proof jwk is not usable -> {}", e)),
+    };
+    let mut val = Validation::new(header.alg);
+    val.required_spec_claims = HashSet::new();
+    val.validate_exp = false;
+    val.validate_aud = false;
+    let claims = match jsonwebtoken::decode::<DpopClaims>(proof, &key, &val) {
+        Ok(data) => data.claims,
+        Err(e) => return dpop_error(&format!("DPoP proof signature is incorrect -> {}", e)),
+    };
+
+    if claims.htm != htm {
+        return dpop_error(&format!("DPoP proof htm '{}' does not match", claims.htm));
+    }
+    let claimed_htu = claims.htu.split(['?', '#']).next().unwrap_or_default();
+    if claimed_htu != htu {
+        return dpop_error(&format!("DPoP proof htu '{}' does not match", claims.htu));
+    }
+    let now = Utc::now().timestamp();
+    if claims.iat < now - DPOP_PROOF_TTL || claims.iat > now + DPOP_PROOF_TTL {
+        return dpop_error("DPoP proof is not fresh");
+    }
+    if let Some(token) = access_token {
+        let ath = sha256_digest(token);
+        if claims.ath.as_deref() != Some(ath.as_str()) {
+            return dpop_error("DPoP proof ath does not match the access token");
+        }
+    }
+
+    let jkt = jwk.thumbprint(ThumbprintHash::SHA256);
+    Ok((claims, jkt))
+}
+
+fn dpop_error<T>(cause: &str) -> anyhow::Result<T> {
+    let error = Errors::dpop_new(cause, None);
+    error!("{}", error.log());
+    bail!(error)
+}
+
+pub fn ec_public_jwk(secret: &p256::SecretKey) -> Value {
+    let point = secret.public_key().to_encoded_point(false);
+    ec_jwk("P-256", point.x(), point.y())
+}
+
+// Compact JWE using ECDH-ES key agreement on P-256 and A256GCM content encryption (RFC 7518)
+pub fn decrypt_jwe(jwe: &str, secret: &p256::SecretKey) -> anyhow::Result<Vec<u8>> {
+    info!("Decrypting JWE");
+
+    let parts: Vec<&str> = jwe.split('.').collect();
+    let [protected, encrypted_key, iv, ciphertext, tag] = parts.as_slice() else {
+        return jwe_error("Response is not a compact JWE");
+    };
+    if !encrypted_key.is_empty() {
+        return jwe_error("ECDH-ES responses must not carry an encrypted key");
+    }
+    let header: Value = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(protected)?)?;
+    if header["alg"] != "ECDH-ES" || header["enc"] != "A256GCM" {
+        return jwe_error("Only ECDH-ES with A256GCM is supported");
+    }
+    let coord = |name: &str| -> anyhow::Result<Vec<u8>> {
+        let data = header["epk"][name].as_str().unwrap_or_default();
+        Ok(URL_SAFE_NO_PAD.decode(data)?)
+    };
+    let (x, y) = (coord("x")?, coord("y")?);
+    if header["epk"]["crv"] != "P-256" || x.len() != 32 || y.len() != 32 {
+        return jwe_error("Ephemeral key must be a P-256 key");
+    }
+    let point = EncodedPoint::<p256::NistP256>::from_affine_coordinates(
+        x.as_slice().into(),
+        y.as_slice().into(),
+        false,
+    );
+    let epk = p256::PublicKey::from_sec1_bytes(point.as_bytes())?;
+    let shared = p256::ecdh::diffie_hellman(secret.to_nonzero_scalar(), epk.as_affine());
+
+    let party = |name: &str| -> anyhow::Result<Vec<u8>> {
+        let data = header[name].as_str().unwrap_or_default();
+        Ok(URL_SAFE_NO_PAD.decode(data)?)
+    };
+    let cek = concat_kdf(
+        shared.raw_secret_bytes(),
+        "A256GCM",
+        &party("apu")?,
+        &party("apv")?,
+        256,
+    );
+
+    let iv = URL_SAFE_NO_PAD.decode(iv)?;
+    if iv.len() != 12 {
+        return jwe_error("JWE initialization vector must be 96 bits");
+    }
+    let mut msg = URL_SAFE_NO_PAD.decode(ciphertext)?;
+    msg.extend(URL_SAFE_NO_PAD.decode(tag)?);
+    let cipher = Aes256Gcm::new(cek.as_slice().into());
+    let payload = Payload {
+        msg: &msg,
+        aad: protected.as_bytes(),
+    };
+    match cipher.decrypt(Nonce::from_slice(&iv), payload) {
+        Ok(data) => Ok(data),
+        Err(_) => jwe_error("JWE could not be decrypted"),
+    }
+}
+
+// Concat KDF of RFC 7518 section 4.6.2, one SHA-256 round covers keys of up to 256 bits
+fn concat_kdf(shared: &[u8], enc: &str, apu: &[u8], apv: &[u8], bits: u32) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(1u32.to_be_bytes());
+    hasher.update(shared);
+    for data in [enc.as_bytes(), apu, apv] {
+        hasher.update((data.len() as u32).to_be_bytes());
+        hasher.update(data);
+    }
+    hasher.update(bits.to_be_bytes());
+    hasher.finalize()[..bits as usize / 8].to_vec()
+}
+
+fn jwe_error<T>(cause: &str) -> anyhow::Result<T> {
+    let error = Errors::security_new(cause);
+    error!("{}", error.log());
+    bail!(error)
+}
+
+// JSONPath subset used by Presentation Exchange: $, .key, ['key'], [n], [*] and .*
+pub fn json_path<'a>(root: &'a Value, path: &str) -> anyhow::Result<Vec<&'a Value>> {
+    let invalid = || {
+        let error = Errors::format_new(BadFormat::Received, &format!("Invalid JSONPath {}", path));
+        error!("{}", error.log());
+        error
+    };
+    let mut rest = match path.strip_prefix('$') {
+        Some(rest) => rest,
+        None => bail!(invalid()),
+    };
+    let mut nodes = vec![root];
+    while !rest.is_empty() {
+        let (selector, remaining) = if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        } else if let Some(after) = rest.strip_prefix('[') {
+            match after.find(']') {
+                Some(end) => (&after[..end], &after[end + 1..]),
+                None => bail!(invalid()),
+            }
+        } else {
+            bail!(invalid())
+        };
+        let key = selector.trim_matches(|c| c == '\'' || c == '"');
+        nodes = nodes
+            .into_iter()
+            .flat_map(|node| match (key, node) {
+                ("*", Value::Array(items)) => items.iter().collect(),
+                ("*", Value::Object(map)) => map.values().collect(),
+                (_, Value::Array(items)) => key
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| items.get(index))
+                    .into_iter()
+                    .collect(),
+                (_, Value::Object(map)) => map.get(key).into_iter().collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+        rest = remaining;
+    }
+    Ok(nodes)
+}
+
 pub fn get_from_opt<T>(value: &Option<T>, field_name: &str) -> anyhow::Result<T>
 where
     T: Clone + Serialize + DeserializeOwned,
@@ -205,17 +527,6 @@ pub fn is_active(iat: u64) -> anyhow::Result<()> {
     }
 }
 
-pub fn has_expired(exp: u64) -> anyhow::Result<()> {
-    let now = Utc::now().timestamp() as u64;
-    if now <= exp {
-        Ok(())
-    } else {
-        let error = Errors::forbidden_new("Token has expired");
-        error!("{}", error.log());
-        bail!(error);
-    }
-}
-
 pub fn read(path: &str) -> anyhow::Result<String> {
     let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     let full_path = format!("{}/{}", manifest_dir.display(), path);
@@ -228,3 +539,167 @@ pub fn read(path: &str) -> anyhow::Result<String> {
         }
     }
 }
+
+// Written to a temporary file and renamed over the target, so readers never see half a file
+pub fn write(path: &str, data: &str) -> anyhow::Result<()> {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let full_path = manifest_dir.join(path);
+    let mut tmp_path = full_path.clone().into_os_string();
+    tmp_path.push(".tmp");
+    let result = match full_path.parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+    .and_then(|_| fs::File::create(&tmp_path))
+    .and_then(|mut file| {
+        file.write_all(data.as_bytes())
+            .and_then(|_| file.sync_all())
+    })
+    .and_then(|_| fs::rename(&tmp_path, &full_path));
+    match result {
+        Ok(_) => Ok(()),
+        Err(e) => {
+            let error = Errors::write_new(path, &e.to_string());
+            error!("{}", error);
+            bail!(error)
+        }
+    }
+}
+
+// Missing folders are treated as empty
+pub fn list_files(path: &str) -> anyhow::Result<Vec<String>> {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let entries = match fs::read_dir(manifest_dir.join(path)) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            let error = Errors::read_new(path, &e.to_string());
+            error!("{}", error);
+            bail!(error)
+        }
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            files.push(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// Bitstring Status List: index 0 is the left-most bit of the first byte
+pub fn get_status_bit(bits: &[u8], index: i64) -> anyhow::Result<bool> {
+    let (byte, mask) = status_bit_position(bits, index)?;
+    Ok(bits[byte] & mask != 0)
+}
+
+pub fn set_status_bit(bits: &mut [u8], index: i64, value: bool) -> anyhow::Result<()> {
+    let (byte, mask) = status_bit_position(bits, index)?;
+    match value {
+        true => bits[byte] |= mask,
+        false => bits[byte] &= !mask,
+    }
+    Ok(())
+}
+
+// Random unset position, falling back to a scan from a random start once the list fills up
+pub fn random_free_status_index(bits: &[u8], size: i64) -> anyhow::Result<Option<i64>> {
+    let mut rng = rand::rng();
+    for _ in 0..32 {
+        let index = rng.random_range(0..size);
+        if !get_status_bit(bits, index)? {
+            return Ok(Some(index));
+        }
+    }
+    let start = rng.random_range(0..size);
+    for offset in 0..size {
+        let index = (start + offset) % size;
+        if !get_status_bit(bits, index)? {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+fn status_bit_position(bits: &[u8], index: i64) -> anyhow::Result<(usize, u8)> {
+    if index < 0 || index as usize >= bits.len() * 8 {
+        let error = Errors::format_new(
+            BadFormat::Received,
+            &format!("Status list index {} out of range", index),
+        );
+        error!("{}", error.log());
+        bail!(error)
+    }
+    let index = index as usize;
+    Ok((index / 8, 0b1000_0000 >> (index % 8)))
+}
+
+// Multibase base64url (prefix 'u') of the GZIP compressed bitstring
+pub fn encode_status_list(bits: &[u8]) -> anyhow::Result<String> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bits)?;
+    let compressed = encoder.finish()?;
+    Ok(format!("u{}", URL_SAFE_NO_PAD.encode(compressed)))
+}
+
+// Bitstring lists are multibase base64url, StatusList2021 ones plain base64 of the gzip stream
+pub fn decode_status_list(encoded: &str) -> anyhow::Result<Vec<u8>> {
+    let compressed = match encoded.strip_prefix('u') {
+        Some(data) => URL_SAFE_NO_PAD.decode(data)?,
+        None => match STANDARD.decode(encoded) {
+            Ok(data) => data,
+            Err(_) => {
+                let error = Errors::format_new(
+                    BadFormat::Received,
+                    "Encoded status list is neither multibase base64url nor base64",
+                );
+                error!("{}", error.log());
+                bail!(error)
+            }
+        },
+    };
+    // One byte past the limit is enough to tell an oversized list apart
+    let mut bits = Vec::new();
+    GzDecoder::new(compressed.as_slice())
+        .take(MAX_STATUS_LIST_BYTES + 1)
+        .read_to_end(&mut bits)?;
+    if bits.len() as u64 > MAX_STATUS_LIST_BYTES {
+        let error = Errors::format_new(
+            BadFormat::Received,
+            &format!(
+                "Status list is larger than {} bytes once decompressed",
+                MAX_STATUS_LIST_BYTES
+            ),
+        );
+        error!("{}", error.log());
+        bail!(error)
+    }
+    Ok(bits)
+}
+
+pub fn render_qr(data: &str, format: QrFormat) -> anyhow::Result<Vec<u8>> {
+    let code = match QrCode::new(data.as_bytes()) {
+        Ok(data) => data,
+        Err(e) => {
+            let error =
+                Errors::format_new(BadFormat::Received, &format!("Unable to encode QR: {}", e));
+            error!("{}", error.log());
+            bail!(error)
+        }
+    };
+    match format {
+        QrFormat::Svg => {
+            let svg = code.render::<svg::Color>().min_dimensions(256, 256).build();
+            Ok(svg.into_bytes())
+        }
+        QrFormat::Png => {
+            let image = code.render::<Luma<u8>>().min_dimensions(256, 256).build();
+            let mut png = Vec::new();
+            DynamicImage::ImageLuma8(image)
+                .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+            Ok(png)
+        }
+    }
+}
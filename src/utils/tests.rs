@@ -0,0 +1,335 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use super::*;
+use crate::services::resolver::JwkDidResolver;
+use crate::services::signer::KeyMaterial;
+use jsonwebtoken::Header;
+use rsa::rand_core::OsRng;
+
+#[test]
+fn status_bits_are_read_from_the_left() {
+    let mut bits = vec![0u8; 2];
+    set_status_bit(&mut bits, 0, true).unwrap();
+    set_status_bit(&mut bits, 9, true).unwrap();
+    assert_eq!(bits, vec![0b1000_0000, 0b0100_0000]);
+    assert!(get_status_bit(&bits, 0).unwrap());
+    assert!(!get_status_bit(&bits, 1).unwrap());
+    assert!(get_status_bit(&bits, 9).unwrap());
+
+    set_status_bit(&mut bits, 0, false).unwrap();
+    assert_eq!(bits[0], 0);
+}
+
+#[test]
+fn status_bits_out_of_range_are_rejected() {
+    let mut bits = vec![0u8; 2];
+    assert!(get_status_bit(&bits, 16).is_err());
+    assert!(get_status_bit(&bits, -1).is_err());
+    assert!(set_status_bit(&mut bits, 16, true).is_err());
+}
+
+#[test]
+fn status_list_round_trips_through_its_encoding() {
+    let mut bits = vec![0u8; 16_384];
+    set_status_bit(&mut bits, 94_567, true).unwrap();
+    let encoded = encode_status_list(&bits).unwrap();
+    assert!(encoded.starts_with('u'));
+    assert_eq!(decode_status_list(&encoded).unwrap(), bits);
+}
+
+#[test]
+fn status_list_spec_example_decodes_to_an_empty_list() {
+    // Empty 131,072 entry list from the Bitstring Status List spec
+    let encoded = "uH4sIAAAAAAAAA-3BMQEAAADCoPVPbQwfoAAAAAAAAAAAAAAAAAAAAIC3AYbSVKsAQAAA";
+    let bits = decode_status_list(encoded).unwrap();
+    assert_eq!(bits.len(), 16_384);
+    assert!(bits.iter().all(|byte| *byte == 0));
+}
+
+#[test]
+fn status_list_2021_encoding_is_plain_base64() {
+    let bits = vec![0b0010_0000u8; 4];
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&bits).unwrap();
+    let encoded = STANDARD.encode(encoder.finish().unwrap());
+    let decoded = decode_status_list(&encoded).unwrap();
+    assert!(get_status_bit(&decoded, 2).unwrap());
+    assert!(!get_status_bit(&decoded, 3).unwrap());
+}
+
+#[test]
+fn status_list_larger_than_the_limit_is_rejected() {
+    let bits = vec![0u8; MAX_STATUS_LIST_BYTES as usize + 1];
+    let encoded = encode_status_list(&bits).unwrap();
+    assert!(decode_status_list(&encoded).is_err());
+
+    let bits = vec![0u8; MAX_STATUS_LIST_BYTES as usize];
+    let encoded = encode_status_list(&bits).unwrap();
+    assert_eq!(decode_status_list(&encoded).unwrap().len(), bits.len());
+}
+
+#[test]
+fn random_free_status_index_skips_taken_indexes() {
+    let mut bits = vec![0xFFu8; 4];
+    set_status_bit(&mut bits, 21, false).unwrap();
+    assert_eq!(random_free_status_index(&bits, 32).unwrap(), Some(21));
+    set_status_bit(&mut bits, 21, true).unwrap();
+    assert_eq!(random_free_status_index(&bits, 32).unwrap(), None);
+}
+
+const HTU: &str = "https://issuer.example.com/api/v1/issuer/credential";
+
+fn dpop_proof(key: &KeyMaterial, htm: &str, htu: &str, iat: i64, ath: Option<&str>) -> String {
+    let header = Header {
+        typ: Some("dpop+jwt".to_string()),
+        jwk: Some(key.get_jwk()),
+        ..Default::default()
+    };
+    let claims = json!({
+        "jti": uuid::Uuid::new_v4().to_string(),
+        "htm": htm,
+        "htu": htu,
+        "iat": iat,
+        "ath": ath.map(sha256_digest),
+    });
+    key.sign(header, &claims).unwrap()
+}
+
+fn dpop_cause(result: anyhow::Result<(DpopClaims, String)>) -> String {
+    match result.unwrap_err().downcast::<Errors>().unwrap() {
+        Errors::DpopError { cause, .. } => cause,
+        other => panic!("expected a DPoP error, got {:?}", other),
+    }
+}
+
+#[test]
+fn dpop_proof_is_bound_to_its_key() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let proof = dpop_proof(&key, "POST", HTU, Utc::now().timestamp(), None);
+    let (claims, jkt) = validate_dpop_proof(&proof, "POST", HTU, None).unwrap();
+    assert_eq!(claims.htu, HTU);
+    assert_eq!(jkt, key.get_jwk().thumbprint(ThumbprintHash::SHA256));
+}
+
+#[test]
+fn dpop_proof_htm_must_match() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let proof = dpop_proof(&key, "GET", HTU, Utc::now().timestamp(), None);
+    let cause = dpop_cause(validate_dpop_proof(&proof, "POST", HTU, None));
+    assert!(cause.contains("htm"));
+}
+
+#[test]
+fn dpop_proof_htu_ignores_query_and_fragment_only() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let now = Utc::now().timestamp();
+    let proof = dpop_proof(&key, "POST", &format!("{}?format=jwt#top", HTU), now, None);
+    assert!(validate_dpop_proof(&proof, "POST", HTU, None).is_ok());
+
+    let proof = dpop_proof(
+        &key,
+        "POST",
+        "https://issuer.example.com/api/v1/issuer/token",
+        now,
+        None,
+    );
+    let cause = dpop_cause(validate_dpop_proof(&proof, "POST", HTU, None));
+    assert!(cause.contains("htu"));
+}
+
+#[test]
+fn dpop_proof_ath_must_hash_the_access_token() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let now = Utc::now().timestamp();
+    let proof = dpop_proof(&key, "POST", HTU, now, Some("access-token"));
+    assert!(validate_dpop_proof(&proof, "POST", HTU, Some("access-token")).is_ok());
+    let cause = dpop_cause(validate_dpop_proof(
+        &proof,
+        "POST",
+        HTU,
+        Some("other-token"),
+    ));
+    assert!(cause.contains("ath"));
+
+    let proof = dpop_proof(&key, "POST", HTU, now, None);
+    let cause = dpop_cause(validate_dpop_proof(
+        &proof,
+        "POST",
+        HTU,
+        Some("access-token"),
+    ));
+    assert!(cause.contains("ath"));
+}
+
+#[test]
+fn dpop_proof_must_be_fresh() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let stale = Utc::now().timestamp() - DPOP_PROOF_TTL - 1;
+    let proof = dpop_proof(&key, "POST", HTU, stale, None);
+    let cause = dpop_cause(validate_dpop_proof(&proof, "POST", HTU, None));
+    assert!(cause.contains("fresh"));
+}
+
+#[test]
+fn dpop_proof_needs_the_dpop_type() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let header = Header {
+        jwk: Some(key.get_jwk()),
+        ..Default::default()
+    };
+    let claims = json!({"jti": "a", "htm": "POST", "htu": HTU, "iat": Utc::now().timestamp()});
+    let proof = key.sign(header, &claims).unwrap();
+    let cause = dpop_cause(validate_dpop_proof(&proof, "POST", HTU, None));
+    assert!(cause.contains("dpop+jwt"));
+}
+
+// RFC 7518 appendix C: Alice's ephemeral key, Bob's static key and the A128GCM key they agree on
+fn rfc7518_secret(d: &str) -> p256::SecretKey {
+    p256::SecretKey::from_slice(&URL_SAFE_NO_PAD.decode(d).unwrap()).unwrap()
+}
+
+#[test]
+fn concat_kdf_matches_the_rfc_7518_example() {
+    let alice = rfc7518_secret("0_NxaRPUMQoAJt50Gz8YiTr8gRTwyEaCumd-MToTmIo");
+    let bob = rfc7518_secret("VEmDZpDXXK8p8N0Cndsxs924q6nS1RXFASRl6BfUqdw");
+    let shared =
+        p256::ecdh::diffie_hellman(alice.to_nonzero_scalar(), bob.public_key().as_affine());
+    let key = concat_kdf(shared.raw_secret_bytes(), "A128GCM", b"Alice", b"Bob", 128);
+    assert_eq!(URL_SAFE_NO_PAD.encode(key), "VqqN6vgjbSBcIijNcacQGg");
+}
+
+// Encrypts like a wallet answering with direct_post.jwt, the apv in the header may differ from the
+// one the key is derived with
+fn encrypt_jwe(recipient: &p256::PublicKey, enc: &str, header_apv: &str, key_apv: &str) -> String {
+    let ephemeral = p256::SecretKey::random(&mut OsRng);
+    let header = json!({
+        "alg": "ECDH-ES",
+        "enc": enc,
+        "epk": ec_public_jwk(&ephemeral),
+        "apu": URL_SAFE_NO_PAD.encode("wallet"),
+        "apv": URL_SAFE_NO_PAD.encode(header_apv),
+    });
+    let protected = URL_SAFE_NO_PAD.encode(header.to_string());
+    let shared = p256::ecdh::diffie_hellman(ephemeral.to_nonzero_scalar(), recipient.as_affine());
+    let cek = concat_kdf(
+        shared.raw_secret_bytes(),
+        "A256GCM",
+        b"wallet",
+        key_apv.as_bytes(),
+        256,
+    );
+    let iv = [7u8; 12];
+    let payload = Payload {
+        msg: br#"{"vp_token":"token"}"#,
+        aad: protected.as_bytes(),
+    };
+    let sealed = Aes256Gcm::new(cek.as_slice().into())
+        .encrypt(Nonce::from_slice(&iv), payload)
+        .unwrap();
+    let (ciphertext, tag) = sealed.split_at(sealed.len() - 16);
+    format!(
+        "{}..{}.{}.{}",
+        protected,
+        URL_SAFE_NO_PAD.encode(iv),
+        URL_SAFE_NO_PAD.encode(ciphertext),
+        URL_SAFE_NO_PAD.encode(tag)
+    )
+}
+
+#[test]
+fn jwe_round_trips() {
+    let secret = p256::SecretKey::random(&mut OsRng);
+    let jwe = encrypt_jwe(&secret.public_key(), "A256GCM", "nonce", "nonce");
+    let plaintext = decrypt_jwe(&jwe, &secret).unwrap();
+    assert_eq!(plaintext, br#"{"vp_token":"token"}"#);
+}
+
+#[test]
+fn jwe_with_a_tampered_tag_is_rejected() {
+    let secret = p256::SecretKey::random(&mut OsRng);
+    let jwe = encrypt_jwe(&secret.public_key(), "A256GCM", "nonce", "nonce");
+    let (rest, tag) = jwe.rsplit_once('.').unwrap();
+    let mut tag = URL_SAFE_NO_PAD.decode(tag).unwrap();
+    tag[0] ^= 1;
+    let tampered = format!("{}.{}", rest, URL_SAFE_NO_PAD.encode(tag));
+    assert!(decrypt_jwe(&tampered, &secret).is_err());
+}
+
+#[test]
+fn jwe_with_other_party_info_is_rejected() {
+    let secret = p256::SecretKey::random(&mut OsRng);
+    let jwe = encrypt_jwe(&secret.public_key(), "A256GCM", "nonce", "other-nonce");
+    assert!(decrypt_jwe(&jwe, &secret).is_err());
+}
+
+#[test]
+fn jwe_with_another_content_encryption_is_rejected() {
+    let secret = p256::SecretKey::random(&mut OsRng);
+    let jwe = encrypt_jwe(&secret.public_key(), "A128GCM", "nonce", "nonce");
+    assert!(decrypt_jwe(&jwe, &secret).is_err());
+}
+
+#[test]
+fn jwe_for_another_recipient_is_rejected() {
+    let secret = p256::SecretKey::random(&mut OsRng);
+    let other = p256::SecretKey::random(&mut OsRng);
+    let jwe = encrypt_jwe(&other.public_key(), "A256GCM", "nonce", "nonce");
+    assert!(decrypt_jwe(&jwe, &secret).is_err());
+}
+
+fn did_signed(key: &KeyMaterial, claims: Value) -> String {
+    let header = Header {
+        kid: Some(format!("{}#0", key.get_did().unwrap())),
+        ..Default::default()
+    };
+    key.sign(header, &claims).unwrap()
+}
+
+#[tokio::test]
+async fn token_expired_within_the_leeway_is_accepted() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let token = did_signed(&key, json!({ "exp": Utc::now().timestamp() - 30 }));
+    let resolver = JwkDidResolver::new();
+
+    assert!(validate_token::<Value>(&token, None, &resolver, 60)
+        .await
+        .is_ok());
+    let error = validate_token::<Value>(&token, None, &resolver, 0)
+        .await
+        .unwrap_err();
+    match error.downcast::<Errors>().unwrap() {
+        Errors::SecurityError { cause, .. } => assert_eq!(cause, "Token has expired"),
+        other => panic!("expected a security error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn token_not_valid_yet_beyond_the_leeway_is_rejected() {
+    let key = KeyMaterial::generate(SigningAlgorithm::ES256).unwrap();
+    let token = did_signed(&key, json!({ "nbf": Utc::now().timestamp() + 120 }));
+    let resolver = JwkDidResolver::new();
+
+    assert!(validate_token::<Value>(&token, None, &resolver, 60)
+        .await
+        .is_err());
+    assert!(validate_token::<Value>(&token, None, &resolver, 180)
+        .await
+        .is_ok());
+}
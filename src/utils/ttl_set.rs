@@ -0,0 +1,54 @@
+/*
+ *
+ *  * Copyright (C) 2025 - Universidad Politécnica de Madrid - UPM
+ *  *
+ *  * This program is free software: you can redistribute it and/or modify
+ *  * it under the terms of the GNU General Public License as published by
+ *  * the Free Software Foundation, either version 3 of the License, or
+ *  * (at your option) any later version.
+ *  *
+ *  * This program is distributed in the hope that it will be useful,
+ *  * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  * GNU General Public License for more details.
+ *  *
+ *  * You should have received a copy of the GNU General Public License
+ *  * along with this program.  If not, see <https://www.gnu.org/licenses/>.
+ *
+ */
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+// In-memory set whose entries are forgotten once their lifetime has passed
+#[derive(Default)]
+pub struct TtlSet {
+    entries: Mutex<HashMap<String, i64>>,
+}
+
+impl TtlSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Returns false when the value was already present and still alive
+    pub fn insert(&self, value: &str, ttl: i64) -> bool {
+        let now = Utc::now().timestamp();
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, expires_at| *expires_at > now);
+        if entries.contains_key(value) {
+            return false;
+        }
+        entries.insert(value.to_string(), now + ttl);
+        true
+    }
+
+    pub fn contains(&self, value: &str) -> bool {
+        let now = Utc::now().timestamp();
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(value)
+            .is_some_and(|expires_at| *expires_at > now)
+    }
+}